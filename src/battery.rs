@@ -0,0 +1,55 @@
+use std::{fs, path::Path, time::Duration};
+
+#[derive(Clone)]
+pub struct Battery {
+    pub percent: f64,
+    pub status: String,
+    pub time_remaining: Option<Duration>,
+}
+
+fn read_value(dir: &Path, name: &str) -> Option<f64> {
+    fs::read_to_string(dir.join(name)).ok()?.trim().parse().ok()
+}
+
+pub fn read_battery() -> Option<Battery> {
+    let dir = fs::read_dir("/sys/class/power_supply")
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            fs::read_to_string(path.join("type")).is_ok_and(|kind| kind.trim() == "Battery")
+        })?;
+
+    let percent = read_value(&dir, "capacity")?;
+    let status = fs::read_to_string(dir.join("status"))
+        .map(|status| status.trim().to_lowercase())
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let (now, full, rate) = match read_value(&dir, "energy_now") {
+        Some(now) => (
+            now,
+            read_value(&dir, "energy_full"),
+            read_value(&dir, "power_now"),
+        ),
+        None => (
+            read_value(&dir, "charge_now")?,
+            read_value(&dir, "charge_full"),
+            read_value(&dir, "current_now"),
+        ),
+    };
+
+    let time_remaining = rate.filter(|&rate| rate > 0.0).and_then(|rate| {
+        let hours = match status.as_str() {
+            "discharging" => now / rate,
+            "charging" => (full? - now).max(0.0) / rate,
+            _ => return None,
+        };
+        Some(Duration::from_secs_f64(hours * 3600.0))
+    });
+
+    Some(Battery {
+        percent,
+        status,
+        time_remaining,
+    })
+}
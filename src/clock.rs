@@ -1,14 +1,20 @@
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Timelike};
+use clap::ValueEnum;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::Paragraph,
 };
+use serde::Deserialize;
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum Segment {
     Full,
+    Bar,
     Left,
     Center,
     Right,
@@ -17,23 +23,46 @@ pub enum Segment {
 }
 
 impl Segment {
-    pub fn fmt(&self, color: Color) -> Span<'static> {
-        let style = Style::default().bg(color);
-
+    pub fn fmt(&self, color: Color, font: ClockFont) -> Span<'static> {
         match self {
-            Self::Full => Span::styled("      ", style),
-            Self::Left => Span::styled("  ", style),
-            Self::Center => Span::styled("  ", style),
-            Self::Right => Span::styled("  ", style),
-            Self::Sides => Span::styled("  ", style),
+            Self::Full => font.fill(6, color),
+            Self::Bar => font.fill(4, color),
+            Self::Left | Self::Center | Self::Right | Self::Sides => font.fill(2, color),
             Self::Empty => Span::raw("      "),
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ClockFont {
+    Block,
+    Shade,
+    Seven,
+}
+
+impl ClockFont {
+    fn digits(&self) -> &'static [Segment; 50] {
+        match self {
+            ClockFont::Block | ClockFont::Shade => &Character::NUMBERS,
+            ClockFont::Seven => &Character::SEVEN_SEGMENT,
+        }
+    }
+
+    fn fill(&self, width: usize, color: Color) -> Span<'static> {
+        match self {
+            ClockFont::Shade => Span::styled("▒".repeat(width), Style::default().fg(color)),
+            ClockFont::Block | ClockFont::Seven => {
+                Span::styled(" ".repeat(width), Style::default().bg(color))
+            }
+        }
+    }
+}
+
 pub enum Character {
     Num(u32),
     Colon,
+    Blank,
     Empty,
 }
 
@@ -98,75 +127,360 @@ impl Character {
         Segment::Full, // 9
     ];
 
-    pub fn fmt(&self, color: Color, row: usize) -> Vec<Span<'static>> {
+    const SEVEN_SEGMENT: [Segment; 50] = [
+        Segment::Bar,
+        Segment::Sides,
+        Segment::Empty,
+        Segment::Sides,
+        Segment::Bar, // 0
+        Segment::Empty,
+        Segment::Right,
+        Segment::Empty,
+        Segment::Right,
+        Segment::Empty, // 1
+        Segment::Bar,
+        Segment::Right,
+        Segment::Bar,
+        Segment::Left,
+        Segment::Bar, // 2
+        Segment::Bar,
+        Segment::Right,
+        Segment::Bar,
+        Segment::Right,
+        Segment::Bar, // 3
+        Segment::Empty,
+        Segment::Sides,
+        Segment::Bar,
+        Segment::Right,
+        Segment::Empty, // 4
+        Segment::Bar,
+        Segment::Left,
+        Segment::Bar,
+        Segment::Right,
+        Segment::Bar, // 5
+        Segment::Bar,
+        Segment::Left,
+        Segment::Bar,
+        Segment::Sides,
+        Segment::Bar, // 6
+        Segment::Bar,
+        Segment::Right,
+        Segment::Empty,
+        Segment::Right,
+        Segment::Empty, // 7
+        Segment::Bar,
+        Segment::Sides,
+        Segment::Bar,
+        Segment::Sides,
+        Segment::Bar, // 8
+        Segment::Bar,
+        Segment::Sides,
+        Segment::Bar,
+        Segment::Right,
+        Segment::Bar, // 9
+    ];
+
+    pub fn fmt(&self, color: Color, row: usize, font: ClockFont) -> Vec<Span<'static>> {
         match self {
             Self::Num(n) => {
-                let segment = Self::NUMBERS[*n as usize * 5 + row];
+                let segment = font.digits()[*n as usize * 5 + row];
                 match segment {
-                    Segment::Full => vec![segment.fmt(color), Span::raw(" ")],
-                    Segment::Left => vec![segment.fmt(color), Span::raw("     ")],
-                    Segment::Center => vec![Span::raw(" "), segment.fmt(color), Span::raw("  ")],
-                    Segment::Right => vec![Span::raw("    "), segment.fmt(color), Span::raw(" ")],
+                    Segment::Full => vec![segment.fmt(color, font), Span::raw(" ")],
+                    Segment::Bar => vec![Span::raw(" "), segment.fmt(color, font), Span::raw("  ")],
+                    Segment::Left => vec![segment.fmt(color, font), Span::raw("     ")],
+                    Segment::Center => {
+                        vec![Span::raw(" "), segment.fmt(color, font), Span::raw("  ")]
+                    }
+                    Segment::Right => {
+                        vec![Span::raw("    "), segment.fmt(color, font), Span::raw(" ")]
+                    }
                     Segment::Sides => {
                         vec![
-                            segment.fmt(color),
+                            segment.fmt(color, font),
                             Span::raw("  "),
-                            segment.fmt(color),
+                            segment.fmt(color, font),
                             Span::raw(" "),
                         ]
                     }
-                    Segment::Empty => vec![Span::raw("      ")],
+                    Segment::Empty => vec![Span::raw("       ")],
                 }
             }
             Self::Colon => {
                 let segment = Self::COLON[row];
                 match segment {
-                    Segment::Center => vec![Span::raw("  "), segment.fmt(color), Span::raw("  ")],
-                    Segment::Empty => vec![Span::raw("      ")],
+                    Segment::Center => {
+                        vec![Span::raw("  "), segment.fmt(color, font), Span::raw("  ")]
+                    }
                     _ => vec![Span::raw("      ")],
                 }
             }
+            Self::Blank => vec![Span::raw("       ")],
             Self::Empty => vec![Span::raw("      ")],
         }
     }
 }
 
-pub fn render_clock(f: &mut Frame, area: ratatui::prelude::Rect, color: Color) {
-    let time = chrono::Local::now().format("%H:%M:%S").to_string();
-    let date = chrono::Local::now().format("%A, %B %d, %Y").to_string();
-    let mut clock_lines = Vec::new();
+#[derive(Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ClockColorMode {
+    Fixed,
+    Cycle,
+    Load,
+}
+
+impl ClockColorMode {
+    pub fn next(&self) -> Self {
+        match self {
+            ClockColorMode::Fixed => ClockColorMode::Cycle,
+            ClockColorMode::Cycle => ClockColorMode::Load,
+            ClockColorMode::Load => ClockColorMode::Fixed,
+        }
+    }
+}
+
+pub enum ClockColor {
+    Fixed(Color),
+    Cycle,
+    Load(f64),
+}
+
+impl ClockColor {
+    fn resolve(&self, now: &DateTime<Local>) -> Color {
+        match self {
+            ClockColor::Fixed(color) => *color,
+            ClockColor::Cycle => hue_color((now.timestamp_millis() / 200 % 360) as f64),
+            ClockColor::Load(usage) => {
+                let load = (usage / 100.0).clamp(0.0, 1.0);
+                Color::Rgb((255.0 * load) as u8, (255.0 * (1.0 - load)) as u8, 0)
+            }
+        }
+    }
+}
+
+fn hue_color(hue: f64) -> Color {
+    let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    Color::Rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+}
+
+pub struct ClockStyle<'a> {
+    pub color: ClockColor,
+    pub twelve_hour: bool,
+    pub leading_zero: bool,
+    pub date_format: &'a str,
+    pub font: ClockFont,
+    pub scale: bool,
+}
 
+pub fn render_clock(f: &mut Frame, area: Rect, style: &ClockStyle) {
+    let now = Local::now();
+    let color = style.color.resolve(&now);
+    let time_format = if style.twelve_hour {
+        "%I:%M:%S"
+    } else {
+        "%H:%M:%S"
+    };
+    let mut time = now.format(time_format).to_string();
+    if !style.leading_zero && time.starts_with('0') {
+        time.replace_range(..1, " ");
+    }
+    let date = now.format(style.date_format).to_string();
+    let colon_visible = now.second().is_multiple_of(2);
+    let mut clock_lines = big_digits(&time, color, colon_visible, style.font);
+    let meridiem_width = if style.twelve_hour { 3 } else { 0 };
+    let scale = if style.scale {
+        clock_scale(area, lines_width(&clock_lines) + meridiem_width)
+    } else {
+        1
+    };
+    clock_lines = scale_lines(clock_lines, scale);
+    if style.twelve_hour {
+        let meridiem = now.format("%p").to_string();
+        for (row, line) in clock_lines.iter_mut().enumerate() {
+            let text = if row == 0 {
+                format!(" {meridiem}")
+            } else {
+                "   ".to_string()
+            };
+            line.spans
+                .push(Span::styled(text, Style::default().fg(color)));
+        }
+    }
+    let clock_width = lines_width(&clock_lines);
+
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(25),
+            Constraint::Length(5 * scale),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let clock_area = vertical_chunks[1];
+    let clock_area = Rect {
+        x: clock_area.x + clock_area.width.saturating_sub(clock_width) / 2,
+        width: clock_width.min(clock_area.width),
+        ..clock_area
+    };
+    f.render_widget(Paragraph::new(clock_lines), clock_area);
+
+    let date_widget = Paragraph::new(date)
+        .alignment(ratatui::layout::Alignment::Center)
+        .style(Style::default().fg(color));
+    f.render_widget(date_widget, vertical_chunks[3]);
+}
+
+fn lines_width(lines: &[Line]) -> u16 {
+    lines.iter().map(Line::width).max().unwrap_or(0) as u16
+}
+
+fn clock_scale(area: Rect, width: u16) -> u16 {
+    let height = area.height - area.height / 4;
+    (area.width / width.max(1))
+        .min(height.saturating_sub(3) / 5)
+        .max(1)
+}
+
+fn scale_lines(lines: Vec<Line<'static>>, scale: u16) -> Vec<Line<'static>> {
+    if scale <= 1 {
+        return lines;
+    }
+    lines
+        .into_iter()
+        .flat_map(|line| {
+            let spans: Vec<Span<'static>> = line
+                .spans
+                .into_iter()
+                .map(|span| {
+                    let content: String = span
+                        .content
+                        .chars()
+                        .flat_map(|ch| std::iter::repeat_n(ch, scale as usize))
+                        .collect();
+                    Span::styled(content, span.style)
+                })
+                .collect();
+            std::iter::repeat_n(Line::from(spans), scale as usize)
+        })
+        .collect()
+}
+
+fn big_digits(
+    text: &str,
+    color: Color,
+    colon_visible: bool,
+    font: ClockFont,
+) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
     for row in 0..5 {
         let mut line_spans = Vec::new();
-        for ch in time.chars() {
-            let character = if ch == ':' {
+        for ch in text.chars() {
+            let character = if ch == ':' && colon_visible {
                 Character::Colon
             } else if ch.is_ascii_digit() {
                 Character::Num(ch.to_digit(10).unwrap())
+            } else if ch == ' ' {
+                Character::Blank
             } else {
                 Character::Empty
             };
-            line_spans.extend(character.fmt(color, row));
+            line_spans.extend(character.fmt(color, row, font));
+        }
+        lines.push(Line::from(line_spans));
+    }
+    lines
+}
+
+pub struct Stopwatch {
+    started_at: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl Stopwatch {
+    pub fn new() -> Stopwatch {
+        Stopwatch {
+            started_at: None,
+            accumulated: Duration::ZERO,
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        match self.started_at.take() {
+            Some(started_at) => self.accumulated += started_at.elapsed(),
+            None => self.started_at = Some(Instant::now()),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        if self.started_at.is_some() {
+            self.started_at = Some(Instant::now());
         }
-        clock_lines.push(Line::from(line_spans));
     }
 
+    pub fn running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated + self.started_at.map_or(Duration::ZERO, |t| t.elapsed())
+    }
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+pub fn render_stopwatch(
+    f: &mut Frame,
+    area: Rect,
+    stopwatch: &Stopwatch,
+    color: &ClockColor,
+    font: ClockFont,
+    scale: bool,
+) {
+    let color = color.resolve(&Local::now());
+    let elapsed = format_elapsed(stopwatch.elapsed());
+    let digits = big_digits(&elapsed, color, true, font);
+    let scale = if scale {
+        clock_scale(area, lines_width(&digits))
+    } else {
+        1
+    };
+    let status = if stopwatch.running() {
+        "running"
+    } else {
+        "stopped"
+    };
+
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Percentage(25),
-            Constraint::Length(5),
+            Constraint::Length(5 * scale),
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Min(0),
         ])
         .split(area);
 
-    let clock_widget = Paragraph::new(clock_lines).alignment(ratatui::layout::Alignment::Center);
-    f.render_widget(clock_widget, vertical_chunks[1]);
+    let digits_widget =
+        Paragraph::new(scale_lines(digits, scale)).alignment(ratatui::layout::Alignment::Center);
+    f.render_widget(digits_widget, vertical_chunks[1]);
 
-    let date_widget = Paragraph::new(date)
+    let status_widget = Paragraph::new(status)
         .alignment(ratatui::layout::Alignment::Center)
         .style(Style::default().fg(color));
-    f.render_widget(date_widget, vertical_chunks[3]);
+    f.render_widget(status_widget, vertical_chunks[3]);
 }
@@ -0,0 +1,325 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use chrono::format::{Item, StrftimeItems};
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::{
+    clock::{ClockColorMode, ClockFont},
+    theme::ThemeName,
+};
+
+pub const MAX_INTERVAL_SECS: u64 = 60;
+pub const MAX_HISTORY_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub interval: f64,
+    pub history: u64,
+    pub tick: u64,
+    pub per_core: bool,
+    pub vertical: bool,
+    pub memory_available: bool,
+    pub twelve_hour: bool,
+    pub time_format: String,
+    pub status_seconds: bool,
+    pub clock_date_format: String,
+    pub clock_leading_zero: bool,
+    pub si: bool,
+    pub bits: bool,
+    pub fahrenheit: bool,
+    pub confirm_quit: bool,
+    pub smooth: bool,
+    pub mouse: bool,
+    pub idle_timeout: u64,
+    pub all_interfaces: bool,
+    pub iface: Vec<String>,
+    pub exclude_iface: Vec<String>,
+    pub thresholds: [f64; 3],
+    pub severity_labels: bool,
+    pub mem_label: Option<String>,
+    pub load_window: Option<LoadWindow>,
+    pub alerts: Alerts,
+    pub theme: ThemeName,
+    pub layout: LayoutMode,
+    pub padding: u16,
+    pub clock_color: ClockColorMode,
+    pub clock_font: ClockFont,
+    pub clock_scale: bool,
+    pub panels: Panels,
+}
+
+#[derive(Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutMode {
+    Centered,
+    Full,
+}
+
+#[derive(Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+pub enum LoadWindow {
+    #[serde(rename = "1")]
+    #[value(name = "1")]
+    One,
+    #[serde(rename = "5")]
+    #[value(name = "5")]
+    Five,
+    #[serde(rename = "15")]
+    #[value(name = "15")]
+    Fifteen,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct Panels {
+    pub cpu: bool,
+    pub memory: bool,
+    pub swap: bool,
+    pub network: bool,
+    pub battery: bool,
+    pub gpu: bool,
+    pub disk: bool,
+    pub numa: bool,
+    pub sensors: bool,
+    pub kernel: bool,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct Alerts {
+    pub cpu: f64,
+    pub memory: f64,
+    pub swap: f64,
+    pub cpu_warn: f64,
+    pub memory_warn: f64,
+    pub swap_warn: f64,
+    pub oom_available: f64,
+    pub oom_swap: f64,
+    pub bell: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            interval: 2.0,
+            history: 120,
+            tick: 100,
+            per_core: false,
+            vertical: false,
+            memory_available: false,
+            twelve_hour: false,
+            time_format: "%m-%d %H:%M".to_string(),
+            status_seconds: true,
+            clock_date_format: "%A, %B %d, %Y".to_string(),
+            clock_leading_zero: true,
+            si: false,
+            bits: false,
+            fahrenheit: false,
+            confirm_quit: false,
+            smooth: false,
+            mouse: true,
+            idle_timeout: 0,
+            all_interfaces: false,
+            iface: Vec::new(),
+            exclude_iface: Vec::new(),
+            thresholds: [25.0, 50.0, 75.0],
+            severity_labels: false,
+            mem_label: None,
+            load_window: None,
+            alerts: Alerts::default(),
+            theme: ThemeName::Dark,
+            layout: LayoutMode::Centered,
+            padding: 20,
+            clock_color: ClockColorMode::Fixed,
+            clock_font: ClockFont::Block,
+            clock_scale: false,
+            panels: Panels::default(),
+        }
+    }
+}
+
+impl Default for Panels {
+    fn default() -> Self {
+        Panels {
+            cpu: true,
+            memory: true,
+            swap: true,
+            network: true,
+            battery: true,
+            gpu: true,
+            disk: true,
+            numa: true,
+            sensors: true,
+            kernel: true,
+        }
+    }
+}
+
+impl Default for Alerts {
+    fn default() -> Self {
+        Alerts {
+            cpu: 95.0,
+            memory: 95.0,
+            swap: 90.0,
+            cpu_warn: 85.0,
+            memory_warn: 85.0,
+            swap_warn: 75.0,
+            oom_available: 5.0,
+            oom_swap: 90.0,
+            bell: false,
+        }
+    }
+}
+
+pub fn validate_time_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("invalid time format '{format}'"));
+    }
+    Ok(())
+}
+
+pub fn validate_interval(interval: f64) -> Result<(), String> {
+    if !interval.is_finite() || interval <= 0.0 || interval > MAX_INTERVAL_SECS as f64 {
+        return Err(format!(
+            "interval must be a positive number of seconds up to {MAX_INTERVAL_SECS}, got {interval}"
+        ));
+    }
+    Ok(())
+}
+
+pub fn parse_interval(value: &str) -> Result<f64, String> {
+    let value = value.trim();
+    let interval = match value.strip_suffix("ms") {
+        Some(millis) => millis.trim().parse::<f64>().map(|millis| millis / 1000.0),
+        None => value
+            .strip_suffix('s')
+            .unwrap_or(value)
+            .trim()
+            .parse::<f64>(),
+    }
+    .map_err(|_| format!("invalid interval '{value}'"))?;
+    validate_interval(interval)?;
+    Ok(interval)
+}
+
+pub fn validate_history(history: u64) -> Result<(), String> {
+    if history > MAX_HISTORY_SECS {
+        return Err(format!(
+            "history must be at most {MAX_HISTORY_SECS} seconds, got {history}"
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_thresholds(thresholds: &[f64; 3]) -> Result<(), String> {
+    let in_range = thresholds.iter().all(|t| (0.0..=100.0).contains(t));
+    let ascending = thresholds.windows(2).all(|pair| pair[0] < pair[1]);
+    if !in_range || !ascending {
+        return Err(format!(
+            "thresholds must be ascending and within 0-100, got {thresholds:?}"
+        ));
+    }
+    Ok(())
+}
+
+pub fn parse_thresholds(value: &str) -> Result<[f64; 3], String> {
+    let values = value
+        .split(',')
+        .map(|part| part.trim().parse::<f64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| err.to_string())?;
+    let thresholds: [f64; 3] = values
+        .try_into()
+        .map_err(|_| "expected three comma-separated values".to_string())?;
+    validate_thresholds(&thresholds)?;
+    Ok(thresholds)
+}
+
+pub fn validate_padding(padding: u16) -> Result<(), String> {
+    if padding >= 50 {
+        return Err(format!("padding must be below 50 percent, got {padding}"));
+    }
+    Ok(())
+}
+
+pub fn parse_padding(value: &str) -> Result<u16, String> {
+    let padding = value.trim().parse::<u16>().map_err(|err| err.to_string())?;
+    validate_padding(padding)?;
+    Ok(padding)
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("zemon"))
+}
+
+impl Config {
+    pub fn load() -> Result<Config, Box<dyn Error>> {
+        let Some(path) = config_dir().map(|dir| dir.join("config.toml")) else {
+            return Ok(Config::default());
+        };
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let config =
+            toml::from_str(&content).map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_interval_units() {
+        assert_eq!(parse_interval("2"), Ok(2.0));
+        assert_eq!(parse_interval("1.5s"), Ok(1.5));
+        assert_eq!(parse_interval(" 250ms "), Ok(0.25));
+    }
+
+    #[test]
+    fn rejects_invalid_intervals() {
+        assert!(parse_interval("fast").is_err());
+        assert!(parse_interval("0").is_err());
+        assert!(parse_interval("-1s").is_err());
+        assert!(parse_interval("1e30").is_err());
+        assert_eq!(parse_interval("60s"), Ok(60.0));
+        assert!(validate_interval(f64::NAN).is_err());
+        assert!(validate_interval(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn bounds_history() {
+        assert!(validate_history(120).is_ok());
+        assert!(validate_history(MAX_HISTORY_SECS).is_ok());
+        assert!(validate_history(MAX_HISTORY_SECS + 1).is_err());
+    }
+
+    #[test]
+    fn parses_ascending_thresholds() {
+        assert_eq!(parse_thresholds("10, 20,30"), Ok([10.0, 20.0, 30.0]));
+        assert!(parse_thresholds("10,20").is_err());
+        assert!(parse_thresholds("30,20,10").is_err());
+        assert!(parse_thresholds("10,20,120").is_err());
+    }
+
+    #[test]
+    fn parses_padding_below_half() {
+        assert_eq!(parse_padding("0"), Ok(0));
+        assert_eq!(parse_padding("49"), Ok(49));
+        assert!(parse_padding("50").is_err());
+        assert!(parse_padding("-1").is_err());
+    }
+
+    #[test]
+    fn validates_time_formats() {
+        assert!(validate_time_format("%H:%M").is_ok());
+        assert!(validate_time_format("%Q").is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use std::fs;
+
+#[derive(Clone, Copy)]
+pub struct CpuTimes {
+    user: u64,
+    system: u64,
+    iowait: u64,
+    steal: u64,
+    total: u64,
+}
+
+#[derive(Clone)]
+pub struct CpuBreakdown {
+    pub user: f64,
+    pub system: f64,
+    pub iowait: f64,
+    pub steal: f64,
+}
+
+#[derive(Clone, Copy)]
+pub struct KernelCounters {
+    pub context_switches: u64,
+    pub interrupts: u64,
+}
+
+#[derive(Clone)]
+pub struct KernelRates {
+    pub context_switches: f64,
+    pub interrupts: f64,
+}
+
+pub fn read_kernel_counters() -> Option<KernelCounters> {
+    parse_kernel_counters(&fs::read_to_string("/proc/stat").ok()?)
+}
+
+pub fn parse_kernel_counters(stat: &str) -> Option<KernelCounters> {
+    let field = |name: &str| {
+        stat.lines()
+            .find_map(|line| line.strip_prefix(name)?.split_whitespace().next())?
+            .parse()
+            .ok()
+    };
+    Some(KernelCounters {
+        context_switches: field("ctxt ")?,
+        interrupts: field("intr ")?,
+    })
+}
+
+pub fn read_cpu_times() -> Option<CpuTimes> {
+    parse_cpu_times(&fs::read_to_string("/proc/stat").ok()?)
+}
+
+pub fn parse_cpu_times(stat: &str) -> Option<CpuTimes> {
+    let line = stat.lines().find(|line| line.starts_with("cpu "))?;
+    let fields: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .map(|field| field.parse().ok())
+        .collect::<Option<_>>()?;
+    let field = |index: usize| fields.get(index).copied().unwrap_or(0);
+
+    Some(CpuTimes {
+        user: field(0) + field(1),
+        system: field(2) + field(5) + field(6),
+        iowait: field(4),
+        steal: field(7),
+        total: fields.iter().take(8).sum(),
+    })
+}
+
+impl CpuTimes {
+    pub fn breakdown_since(&self, previous: &CpuTimes) -> Option<CpuBreakdown> {
+        let total = self.total.checked_sub(previous.total).filter(|&t| t > 0)? as f64;
+        let percent = |now: u64, before: u64| now.saturating_sub(before) as f64 / total * 100.0;
+        Some(CpuBreakdown {
+            user: percent(self.user, previous.user),
+            system: percent(self.system, previous.system),
+            iowait: percent(self.iowait, previous.iowait),
+            steal: percent(self.steal, previous.steal),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAT: &str = "cpu  100 20 30 400 50 6 4 10 0 0\n\
+                        cpu0 50 10 15 200 25 3 2 5 0 0\n\
+                        intr 12345 1 2 3\n\
+                        ctxt 67890\n\
+                        btime 1700000000\n";
+
+    #[test]
+    fn parses_aggregate_cpu_line() {
+        let times = parse_cpu_times(STAT).unwrap();
+        assert_eq!(times.user, 120);
+        assert_eq!(times.system, 40);
+        assert_eq!(times.iowait, 50);
+        assert_eq!(times.steal, 10);
+        assert_eq!(times.total, 620);
+    }
+
+    #[test]
+    fn rejects_missing_or_malformed_cpu_line() {
+        assert!(parse_cpu_times("cpu0 1 2 3\n").is_none());
+        assert!(parse_cpu_times("cpu  1 two 3\n").is_none());
+    }
+
+    #[test]
+    fn breakdown_is_relative_to_previous_sample() {
+        let previous = parse_cpu_times("cpu  0 0 0 0 0 0 0 0\n").unwrap();
+        let current = parse_cpu_times("cpu  30 0 10 50 10 0 0 0\n").unwrap();
+        let breakdown = current.breakdown_since(&previous).unwrap();
+        assert_eq!(breakdown.user, 30.0);
+        assert_eq!(breakdown.system, 10.0);
+        assert_eq!(breakdown.iowait, 10.0);
+        assert_eq!(breakdown.steal, 0.0);
+        assert!(current.breakdown_since(&current).is_none());
+    }
+
+    #[test]
+    fn parses_kernel_counters() {
+        let counters = parse_kernel_counters(STAT).unwrap();
+        assert_eq!(counters.context_switches, 67890);
+        assert_eq!(counters.interrupts, 12345);
+        assert!(parse_kernel_counters("ctxt 1\n").is_none());
+    }
+}
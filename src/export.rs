@@ -0,0 +1,681 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, SyncSender, TrySendError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use chrono::Local;
+use clap::ValueEnum;
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+use serde::Serialize;
+
+use zemon::units::format_gigabytes;
+
+use crate::{App, Severity, Tab, format_rate, get_gauge_color, ui};
+
+const SNAPSHOT_INTERVAL: Duration = Duration::from_millis(500);
+const OUTPUT_BACKLOG: usize = 64;
+pub const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+const EXPORT_WIDTH: u16 = 100;
+const EXPORT_HEIGHT: u16 = 40;
+const CELL_WIDTH: f64 = 8.4;
+const CELL_HEIGHT: f64 = 17.0;
+const DEFAULT_FG: &str = "#d0d0d0";
+const DEFAULT_BG: &str = "#1c1c1c";
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Bar,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum Markup {
+    Ansi,
+    Pango,
+}
+
+#[derive(Serialize)]
+pub struct Sample {
+    pub timestamp: String,
+    pub cpu: f64,
+    pub memory: f64,
+    pub memory_used: u64,
+    pub swap: f64,
+    pub swap_used: u64,
+    pub network_download: f64,
+    pub network_upload: f64,
+    pub load_avg: [f64; 3],
+}
+
+impl Sample {
+    pub fn from_app(app: &App) -> Sample {
+        Sample {
+            timestamp: Local::now().to_rfc3339(),
+            cpu: app.metrics.cpu_usage,
+            memory: app.metrics.memory_percent,
+            memory_used: app.metrics.used_memory,
+            swap: app.metrics.swap_percent,
+            swap_used: app.metrics.used_swap,
+            network_download: app.metrics.network_download_rate,
+            network_upload: app.metrics.network_upload_rate,
+            load_avg: [
+                app.metrics.load_avg_1,
+                app.metrics.load_avg_5,
+                app.metrics.load_avg_15,
+            ],
+        }
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, values: &[(&str, f64)]| {
+            let _ = writeln!(out, "# HELP zemon_{name} {help}");
+            let _ = writeln!(out, "# TYPE zemon_{name} gauge");
+            for (labels, value) in values {
+                let _ = writeln!(out, "zemon_{name}{labels} {value}");
+            }
+        };
+
+        gauge("cpu_usage_percent", "Global CPU usage.", &[("", self.cpu)]);
+        gauge(
+            "memory_usage_percent",
+            "Memory usage.",
+            &[("", self.memory)],
+        );
+        gauge(
+            "memory_used_bytes",
+            "Used memory in bytes.",
+            &[("", self.memory_used as f64)],
+        );
+        gauge("swap_usage_percent", "Swap usage.", &[("", self.swap)]);
+        gauge(
+            "swap_used_bytes",
+            "Used swap in bytes.",
+            &[("", self.swap_used as f64)],
+        );
+        gauge(
+            "network_receive_bytes_per_second",
+            "Network download rate.",
+            &[("", self.network_download)],
+        );
+        gauge(
+            "network_transmit_bytes_per_second",
+            "Network upload rate.",
+            &[("", self.network_upload)],
+        );
+        gauge(
+            "load_average",
+            "System load average.",
+            &[
+                ("{window=\"1m\"}", self.load_avg[0]),
+                ("{window=\"5m\"}", self.load_avg[1]),
+                ("{window=\"15m\"}", self.load_avg[2]),
+            ],
+        );
+        out
+    }
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{:.1},{:.1},{:.1},{:.1},{:.1},{:.2}",
+            self.timestamp,
+            self.cpu,
+            self.memory,
+            self.swap,
+            self.network_upload / 1024.0,
+            self.network_download / 1024.0,
+            self.load_avg[0]
+        )
+    }
+}
+
+pub fn open_log(path: &Path) -> Result<File, Box<dyn Error>> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+    if file.metadata()?.len() == 0 {
+        writeln!(
+            file,
+            "timestamp,cpu,mem_percent,swap_percent,up_kbps,down_kbps,load1"
+        )?;
+    }
+    Ok(file)
+}
+
+pub fn write_log(file: &mut File, app: &App) -> io::Result<()> {
+    writeln!(file, "{}", Sample::from_app(app).to_csv_row())
+}
+
+pub struct Output {
+    sender: SyncSender<String>,
+    errors: Receiver<String>,
+    writer: JoinHandle<()>,
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+impl Output {
+    pub fn open(path: PathBuf) -> Result<Output, Box<dyn Error>> {
+        let regular = std::fs::metadata(&path).map_or(true, |metadata| metadata.is_file());
+        let file = if regular {
+            Some(open_append(&path).map_err(|err| format!("{}: {err}", path.display()))?)
+        } else {
+            None
+        };
+        let (sender, receiver) = mpsc::sync_channel::<String>(OUTPUT_BACKLOG);
+        let (error_sender, errors) = mpsc::channel();
+        let writer = thread::spawn(move || {
+            let result = file
+                .map_or_else(|| open_append(&path), Ok)
+                .and_then(|mut file| {
+                    for line in receiver {
+                        writeln!(file, "{line}")?;
+                        file.flush()?;
+                    }
+                    Ok(())
+                });
+            if let Err(err) = result {
+                let _ = error_sender.send(format!("{}: {err}", path.display()));
+            }
+        });
+        Ok(Output {
+            sender,
+            errors,
+            writer,
+        })
+    }
+
+    pub fn send(&self, sample: &Sample) -> Result<(), String> {
+        let line = serde_json::to_string(sample).map_err(|err| err.to_string())?;
+        match self.sender.try_send(line) {
+            Ok(()) | Err(TrySendError::Full(_)) => Ok(()),
+            Err(TrySendError::Disconnected(_)) => Err(self
+                .errors
+                .try_recv()
+                .unwrap_or_else(|_| "output closed".to_string())),
+        }
+    }
+
+    pub fn finish(self) {
+        drop(self.sender);
+        let _ = self.writer.join();
+    }
+
+    pub fn finish_within(self, timeout: Duration) {
+        drop(self.sender);
+        let deadline = Instant::now() + timeout;
+        while !self.writer.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+pub fn write_output(app: &mut App) {
+    if let Some(output) = app.output.take() {
+        match output.send(&Sample::from_app(app)) {
+            Ok(()) => app.output = Some(output),
+            Err(err) => app.message = Some(format!("Output stopped: {err}")),
+        }
+    }
+}
+
+pub fn run_json(app: &mut App, watch: bool) -> Result<(), Box<dyn Error>> {
+    let mut stdout = io::stdout().lock();
+    let streaming = app.output.is_some();
+    loop {
+        thread::sleep(app.refresh_interval);
+        app.sample();
+
+        if !streaming {
+            serde_json::to_writer(&mut stdout, &Sample::from_app(app))?;
+            writeln!(stdout)?;
+            stdout.flush()?;
+        } else if app.output.is_none() {
+            return Err(app.message.take().unwrap_or_default().into());
+        }
+
+        if !watch {
+            if let Some(output) = app.output.take() {
+                output.finish();
+            }
+            return Ok(());
+        }
+    }
+}
+
+pub fn status_line(app: &App) -> String {
+    format!(
+        "CPU {:.0}% MEM {:.0}% ↓{} ↑{}",
+        app.metrics.cpu_usage,
+        app.metrics.memory_percent,
+        app.network_reading(
+            app.metrics.network_download_rate,
+            app.metrics.network_counter_reset
+        ),
+        app.network_reading(
+            app.metrics.network_upload_rate,
+            app.metrics.network_counter_reset
+        )
+    )
+}
+
+pub fn run_watch(app: &mut App) -> Result<(), Box<dyn Error>> {
+    let mut stdout = io::stdout().lock();
+    loop {
+        thread::sleep(app.refresh_interval);
+        app.sample();
+
+        write!(stdout, "\r{}\x1b[K", status_line(app))?;
+        stdout.flush()?;
+    }
+}
+
+fn ansi_color(color: Color, background: bool) -> String {
+    let base = if background { 40 } else { 30 };
+    match color {
+        Color::Reset => format!("{}", base + 9),
+        Color::Black => format!("{base}"),
+        Color::Red => format!("{}", base + 1),
+        Color::Green => format!("{}", base + 2),
+        Color::Yellow => format!("{}", base + 3),
+        Color::Blue => format!("{}", base + 4),
+        Color::Magenta => format!("{}", base + 5),
+        Color::Cyan => format!("{}", base + 6),
+        Color::Gray => format!("{}", base + 7),
+        Color::DarkGray => format!("{}", base + 60),
+        Color::LightRed => format!("{}", base + 61),
+        Color::LightGreen => format!("{}", base + 62),
+        Color::LightYellow => format!("{}", base + 63),
+        Color::LightBlue => format!("{}", base + 64),
+        Color::LightMagenta => format!("{}", base + 65),
+        Color::LightCyan => format!("{}", base + 66),
+        Color::White => format!("{}", base + 67),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+fn bar_segment(out: &mut String, markup: Markup, text: &str, fg: Color, bg: Color) {
+    match markup {
+        Markup::Ansi => {
+            let _ = write!(
+                out,
+                "\x1b[{};{}m{text}",
+                ansi_color(fg, false),
+                ansi_color(bg, true)
+            );
+        }
+        Markup::Pango => {
+            let _ = write!(
+                out,
+                r#"<span foreground="{}""#,
+                svg_color(fg).unwrap_or_else(|| DEFAULT_FG.to_string())
+            );
+            if let Some(bg) = svg_color(bg) {
+                let _ = write!(out, r#" background="{bg}""#);
+            }
+            let _ = write!(out, ">{}</span>", escape_xml(text));
+        }
+    }
+}
+
+pub fn bar_line(app: &App, markup: Markup) -> String {
+    let segments = [
+        (
+            format!(" CPU {:.0}% ", app.metrics.cpu_usage),
+            get_gauge_color(app.metrics.cpu_usage, &app.thresholds, &app.theme),
+        ),
+        (
+            format!(" MEM {:.0}% ", app.metrics.memory_percent),
+            get_gauge_color(app.metrics.memory_percent, &app.thresholds, &app.theme),
+        ),
+        (
+            format!(
+                " ↓{} ↑{} ",
+                app.network_reading(
+                    app.metrics.network_download_rate,
+                    app.metrics.network_counter_reset
+                ),
+                app.network_reading(
+                    app.metrics.network_upload_rate,
+                    app.metrics.network_counter_reset
+                )
+            ),
+            app.theme.muted,
+        ),
+    ];
+
+    let mut out = String::new();
+    for (index, (text, bg)) in segments.iter().enumerate() {
+        bar_segment(&mut out, markup, text, Color::Black, *bg);
+        let next = segments.get(index + 1).map_or(Color::Reset, |(_, bg)| *bg);
+        bar_segment(&mut out, markup, "\u{e0b0}", *bg, next);
+    }
+    if markup == Markup::Ansi {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+pub fn run_bar(app: &mut App, markup: Markup, watch: bool) -> Result<(), Box<dyn Error>> {
+    let mut stdout = io::stdout().lock();
+    loop {
+        thread::sleep(app.refresh_interval);
+        app.sample();
+
+        writeln!(stdout, "{}", bar_line(app, markup))?;
+        stdout.flush()?;
+
+        if !watch {
+            return Ok(());
+        }
+    }
+}
+
+pub fn snapshot_text(app: &App) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", app.host_info);
+    let _ = writeln!(
+        out,
+        "CPU      {:5.1}%  load {:.2} {:.2} {:.2}",
+        app.metrics.cpu_usage,
+        app.metrics.load_avg_1,
+        app.metrics.load_avg_5,
+        app.metrics.load_avg_15
+    );
+    let _ = writeln!(
+        out,
+        "Memory   {:5.1}%  {} / {}",
+        app.metrics.memory_percent,
+        format_gigabytes(app.metrics.used_memory, app.si),
+        format_gigabytes(app.metrics.total_memory, app.si)
+    );
+    let _ = writeln!(
+        out,
+        "Swap     {:5.1}%  {} / {}",
+        app.metrics.swap_percent,
+        format_gigabytes(app.metrics.used_swap, app.si),
+        format_gigabytes(app.metrics.total_swap, app.si)
+    );
+    let _ = writeln!(
+        out,
+        "Network  down {}  up {}",
+        app.network_reading(
+            app.metrics.network_download_rate,
+            app.metrics.network_counter_reset
+        ),
+        app.network_reading(
+            app.metrics.network_upload_rate,
+            app.metrics.network_counter_reset
+        )
+    );
+    let _ = writeln!(
+        out,
+        "Disk     read {}  write {}",
+        format_rate(app.metrics.disk_read_rate),
+        format_rate(app.metrics.disk_write_rate)
+    );
+    out
+}
+
+pub fn run_once(app: &mut App) -> Result<(), Box<dyn Error>> {
+    app.refresh_interval = SNAPSHOT_INTERVAL;
+    thread::sleep(SNAPSHOT_INTERVAL);
+    app.sample();
+
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(snapshot_text(app).as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+pub fn run_check(app: &mut App) -> Result<i32, Box<dyn Error>> {
+    app.refresh_interval = SNAPSHOT_INTERVAL;
+    thread::sleep(SNAPSHOT_INTERVAL);
+    app.sample();
+
+    let alerts = &app.alerts;
+    let readings = [
+        ("cpu", app.metrics.cpu_usage, alerts.cpu_warn, alerts.cpu),
+        (
+            "memory",
+            app.metrics.memory_percent,
+            alerts.memory_warn,
+            alerts.memory,
+        ),
+        (
+            "swap",
+            app.metrics.swap_percent,
+            alerts.swap_warn,
+            alerts.swap,
+        ),
+    ];
+    let severities = readings.map(|(_, value, warn, crit)| Severity::between(value, warn, crit));
+    let worst = severities
+        .iter()
+        .copied()
+        .fold(Severity::Ok, |worst, severity| {
+            if severity > worst { severity } else { worst }
+        });
+    let status = match worst {
+        Severity::Ok => "OK",
+        Severity::Warn => "WARNING",
+        Severity::Crit => "CRITICAL",
+    };
+    let summary = readings
+        .iter()
+        .zip(severities)
+        .map(|((name, value, ..), severity)| format!("{name} {value:.1}% {}", severity.label()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let perfdata = readings
+        .iter()
+        .map(|(name, value, warn, crit)| format!("{name}={value:.1}%;{warn};{crit}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "ZEMON {status} - {summary} | {perfdata}")?;
+    stdout.flush()?;
+    Ok(match worst {
+        Severity::Ok => 0,
+        Severity::Warn => 1,
+        Severity::Crit => 2,
+    })
+}
+
+pub fn run_export(app: &mut App, path: &Path) -> Result<(), Box<dyn Error>> {
+    app.current_tab = Tab::Overview;
+    app.refresh_interval = SNAPSHOT_INTERVAL;
+    thread::sleep(SNAPSHOT_INTERVAL);
+    app.sample();
+
+    let mut terminal = Terminal::new(TestBackend::new(EXPORT_WIDTH, EXPORT_HEIGHT))?;
+    terminal.draw(|f| ui(f, app))?;
+    std::fs::write(path, buffer_to_svg(terminal.backend().buffer()))?;
+    Ok(())
+}
+
+fn svg_color(color: Color) -> Option<String> {
+    const ANSI: [&str; 16] = [
+        "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+        "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+    ];
+    let hex = match color {
+        Color::Reset => return None,
+        Color::Black => ANSI[0],
+        Color::Red => ANSI[1],
+        Color::Green => ANSI[2],
+        Color::Yellow => ANSI[3],
+        Color::Blue => ANSI[4],
+        Color::Magenta => ANSI[5],
+        Color::Cyan => ANSI[6],
+        Color::Gray => ANSI[7],
+        Color::DarkGray => ANSI[8],
+        Color::LightRed => ANSI[9],
+        Color::LightGreen => ANSI[10],
+        Color::LightYellow => ANSI[11],
+        Color::LightBlue => ANSI[12],
+        Color::LightMagenta => ANSI[13],
+        Color::LightCyan => ANSI[14],
+        Color::White => ANSI[15],
+        Color::Indexed(index) if index < 16 => ANSI[index as usize],
+        Color::Indexed(index) if index >= 232 => {
+            let level = 8 + (index - 232) * 10;
+            return Some(format!("#{level:02x}{level:02x}{level:02x}"));
+        }
+        Color::Indexed(index) => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+            return Some(format!(
+                "#{:02x}{:02x}{:02x}",
+                level(index / 36),
+                level(index / 6 % 6),
+                level(index % 6)
+            ));
+        }
+        Color::Rgb(r, g, b) => return Some(format!("#{r:02x}{g:02x}{b:02x}")),
+    };
+    Some(hex.to_string())
+}
+
+fn cell_colors(cell: &Cell) -> (String, Option<String>) {
+    let fg = svg_color(cell.fg);
+    let bg = svg_color(cell.bg);
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (
+            bg.unwrap_or_else(|| DEFAULT_BG.to_string()),
+            Some(fg.unwrap_or_else(|| DEFAULT_FG.to_string())),
+        )
+    } else {
+        (fg.unwrap_or_else(|| DEFAULT_FG.to_string()), bg)
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn buffer_to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let width = area.width as f64 * CELL_WIDTH;
+    let height = area.height as f64 * CELL_HEIGHT;
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" font-family="monospace" font-size="14">"#
+    );
+    let _ = writeln!(
+        out,
+        r#"<rect width="100%" height="100%" fill="{DEFAULT_BG}"/>"#
+    );
+
+    for y in 0..area.height {
+        let mut x = 0;
+        while x < area.width {
+            let cell = &buffer[(area.x + x, area.y + y)];
+            let style = (cell_colors(cell), cell.modifier.contains(Modifier::BOLD));
+            let start = x;
+            let mut text = String::new();
+            while x < area.width {
+                let cell = &buffer[(area.x + x, area.y + y)];
+                if (cell_colors(cell), cell.modifier.contains(Modifier::BOLD)) != style {
+                    break;
+                }
+                if !cell.skip {
+                    text.push_str(cell.symbol());
+                }
+                x += 1;
+            }
+
+            let ((fg, bg), bold) = style;
+            let left = start as f64 * CELL_WIDTH;
+            let top = y as f64 * CELL_HEIGHT;
+            let run_width = (x - start) as f64 * CELL_WIDTH;
+            if let Some(bg) = bg {
+                let _ = writeln!(
+                    out,
+                    r#"<rect x="{left:.1}" y="{top:.1}" width="{run_width:.1}" height="{CELL_HEIGHT}" fill="{bg}"/>"#
+                );
+            }
+            if !text.trim().is_empty() {
+                let weight = if bold { " font-weight=\"bold\"" } else { "" };
+                let _ = writeln!(
+                    out,
+                    r#"<text x="{left:.1}" y="{:.1}" fill="{fg}"{weight} textLength="{run_width:.1}" lengthAdjust="spacingAndGlyphs" xml:space="preserve">{}</text>"#,
+                    top + CELL_HEIGHT * 0.8,
+                    escape_xml(&text)
+                );
+            }
+        }
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+pub fn run_prometheus(app: &mut App, port: u16) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    listener.set_nonblocking(true)?;
+
+    loop {
+        if app.last_update.elapsed() >= app.refresh_interval {
+            app.sample();
+        }
+
+        loop {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    if let Err(err) = serve_metrics(stream, app) {
+                        eprintln!("{err}");
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+fn serve_metrics(stream: TcpStream, app: &App) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+
+    let (status, body) = if path == "/metrics" {
+        ("200 OK", Sample::from_app(app).to_prometheus())
+    } else {
+        ("404 Not Found", "Not Found\n".to_string())
+    };
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
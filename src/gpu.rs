@@ -0,0 +1,47 @@
+#[cfg(feature = "gpu")]
+pub use nvml_wrapper::Nvml;
+#[cfg(feature = "gpu")]
+use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+#[cfg(not(feature = "gpu"))]
+pub enum Nvml {}
+
+#[derive(Clone)]
+pub struct Gpu {
+    pub name: String,
+    pub utilization: u32,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    pub temperature: Option<u32>,
+}
+
+#[cfg(feature = "gpu")]
+pub fn init() -> Option<Nvml> {
+    let nvml = Nvml::init().ok()?;
+    (nvml.device_count().ok()? > 0).then_some(nvml)
+}
+
+#[cfg(not(feature = "gpu"))]
+pub fn init() -> Option<Nvml> {
+    None
+}
+
+#[cfg(feature = "gpu")]
+pub fn read_gpu(nvml: &Nvml) -> Option<Gpu> {
+    let device = nvml.device_by_index(0).ok()?;
+    let utilization = device.utilization_rates().ok()?;
+    let memory = device.memory_info().ok()?;
+
+    Some(Gpu {
+        name: device.name().unwrap_or_else(|_| "GPU".to_string()),
+        utilization: utilization.gpu,
+        memory_used: memory.used,
+        memory_total: memory.total,
+        temperature: device.temperature(TemperatureSensor::Gpu).ok(),
+    })
+}
+
+#[cfg(not(feature = "gpu"))]
+pub fn read_gpu(nvml: &Nvml) -> Option<Gpu> {
+    match *nvml {}
+}
@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+pub mod battery;
+pub mod cpustat;
+pub mod gpu;
+pub mod metrics;
+pub mod numa;
+pub mod units;
+pub mod vmstat;
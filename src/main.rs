@@ -1,41 +1,271 @@
 mod clock;
+mod config;
+mod export;
+mod process;
+mod state;
+mod stats;
+mod theme;
+mod worker;
 
+use chrono::{DateTime, Local};
 use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    cursor,
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, MouseButton,
+        MouseEventKind,
+    },
     execute,
+    style::Print,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
     Frame, Terminal,
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout},
-    style::{Color, Style},
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Position, Rect},
+    style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, RenderDirection, Sparkline, Wrap},
+    widgets::{
+        Axis, Bar, BarChart, Block, Chart, Clear, Dataset, Gauge, GraphType, Paragraph, Row, Table,
+        Tabs,
+        canvas::{Canvas, Line as CanvasLine},
+    },
 };
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::VecDeque,
     error::Error,
-    io,
+    fs::File,
+    io::{self, IsTerminal},
+    mem, panic,
+    path::PathBuf,
     time::{Duration, Instant},
 };
-use sysinfo::{Networks, System};
+use sysinfo::{Pid, Signal, System};
+
+use crate::{
+    clock::{ClockColor, ClockColorMode, ClockFont, ClockStyle, Stopwatch},
+    config::{Alerts, Config, LayoutMode, LoadWindow, Panels},
+    process::{PendingKill, ProcessView},
+    state::State,
+    stats::Stats,
+    theme::{Theme, ThemeName},
+    worker::{Request, Worker},
+};
+use zemon::{
+    metrics::{InterfaceFilter, Metrics, ProcessInfo, Sampler, Scope},
+    units::{format_bit_rate, format_event_rate, format_gigabytes, format_megabytes},
+};
 
 #[derive(Parser)]
 #[command(name = "zemon")]
 #[command(about = "A simple system monitor using ratatui")]
 struct Args {
-    /// Refresh interval in seconds
-    #[arg(short, long, default_value = "2")]
-    interval: u64,
+    /// Refresh interval in seconds, or with a unit like "500ms" [default: 2]
+    #[arg(short, long, value_parser = config::parse_interval)]
+    interval: Option<f64>,
+
+    /// Show one gauge per CPU core instead of the aggregate gauge
+    #[arg(long)]
+    per_core: bool,
+
+    /// Draw CPU, memory and swap as vertical bars side by side
+    #[arg(long)]
+    vertical: bool,
+
+    /// Compute memory usage from available memory, excluding reclaimable cache
+    #[arg(long)]
+    memory_available: bool,
+
+    /// Use a 12-hour clock with AM/PM
+    #[arg(long)]
+    twelve_hour: bool,
+
+    /// Blank the leading zero of the big clock hour
+    #[arg(long)]
+    no_leading_zero: bool,
+
+    /// Hide seconds in the status line clock
+    #[arg(long)]
+    no_seconds: bool,
+
+    /// Leave the mouse to the terminal so text selection keeps working
+    #[arg(long)]
+    no_mouse: bool,
+
+    /// Use decimal (GB) instead of binary (GiB) memory units
+    #[arg(long)]
+    si: bool,
+
+    /// Show network rates in bits per second
+    #[arg(long)]
+    bits: bool,
+
+    /// Length of the network history chart in seconds [default: 120]
+    #[arg(long, value_name = "SECONDS")]
+    history: Option<u64>,
+
+    /// Input poll timeout in milliseconds; lower is more responsive but uses more CPU [default: 100]
+    #[arg(long, value_name = "MS")]
+    tick: Option<u64>,
+
+    /// Show temperatures in Fahrenheit
+    #[arg(long)]
+    fahrenheit: bool,
+
+    /// Require pressing q twice to quit
+    #[arg(long)]
+    confirm_quit: bool,
+
+    /// Ease gauges toward new samples instead of jumping
+    #[arg(long)]
+    smooth: bool,
+
+    /// Dim the display after this many seconds without a keypress [default: 0, never]
+    #[arg(long, value_name = "SECONDS")]
+    idle_timeout: Option<u64>,
+
+    /// Include loopback interfaces in network figures
+    #[arg(long)]
+    all_interfaces: bool,
+
+    /// Only count these network interfaces (glob patterns allowed)
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    iface: Option<Vec<String>>,
+
+    /// Leave these network interfaces out of the totals (glob patterns allowed)
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    exclude_iface: Option<Vec<String>>,
+
+    /// strftime format for timestamps in the status line [default: "%m-%d %H:%M"]
+    #[arg(long, value_name = "FORMAT")]
+    time_format: Option<String>,
+
+    /// strftime format for the date under the big clock [default: "%A, %B %d, %Y"]
+    #[arg(long, value_name = "FORMAT")]
+    clock_date_format: Option<String>,
+
+    /// Gauge color breakpoints in percent [default: 25,50,75]
+    #[arg(long, value_name = "LOW,MID,HIGH", value_parser = config::parse_thresholds)]
+    thresholds: Option<[f64; 3]>,
+
+    /// Append OK / WARN / CRIT to gauge labels
+    #[arg(long)]
+    severity_labels: bool,
+
+    /// Memory gauge label; placeholders {used} {total} {cache} {available} {pct}
+    #[arg(long, value_name = "TEMPLATE")]
+    mem_label: Option<String>,
+
+    /// Color all load averages by the 1, 5 or 15 minute value instead of each by its own
+    #[arg(long, value_enum, value_name = "MINUTES")]
+    load_window: Option<LoadWindow>,
+
+    /// Big clock color mode [default: fixed]
+    #[arg(long, value_enum)]
+    clock_color: Option<ClockColorMode>,
+
+    /// Big clock digit style [default: block]
+    #[arg(long, value_enum)]
+    clock_font: Option<ClockFont>,
+
+    /// Grow the big clock digits to fill the terminal
+    #[arg(long)]
+    clock_scale: bool,
+
+    /// Color theme [default: dark]
+    #[arg(long, value_enum)]
+    theme: Option<ThemeName>,
+
+    /// Center the panels or stretch them across the terminal [default: centered]
+    #[arg(long, value_enum)]
+    layout: Option<LayoutMode>,
+
+    /// Horizontal margin on each side of the centered layout, in percent [default: 20]
+    #[arg(long, value_name = "PCT", value_parser = config::parse_padding)]
+    padding: Option<u16>,
+
+    /// Start with the full-screen clock
+    #[arg(long)]
+    clock: bool,
+
+    /// Never refresh on a timer; press r to take each sample
+    #[arg(long)]
+    manual: bool,
+
+    /// Append a CSV row of metrics to this file on every refresh
+    #[arg(long, value_name = "PATH")]
+    log: Option<PathBuf>,
+
+    /// Track the CPU and memory of this process instead of the global CPU
+    #[arg(long, value_name = "PID")]
+    pid: Option<u32>,
+
+    /// Track the first process whose name contains this text
+    #[arg(long, value_name = "SUBSTR", conflicts_with = "pid")]
+    name: Option<String>,
+
+    /// Quit when the tracked process exits
+    #[arg(long)]
+    exit_with_process: bool,
+
+    /// Stream JSON samples to this file or named pipe on every refresh
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Print a JSON sample to stdout instead of starting the TUI
+    #[arg(long)]
+    json: bool,
+
+    /// Serve Prometheus metrics on the given port instead of starting the TUI
+    #[arg(long, value_name = "PORT", conflicts_with = "json")]
+    prometheus: Option<u16>,
+
+    /// Print a one-shot text snapshot instead of starting the TUI
+    #[arg(long, conflicts_with_all = ["json", "prometheus"])]
+    once: bool,
+
+    /// With --once, print a check summary and exit 1 on warning or 2 on critical alert thresholds
+    #[arg(long, requires = "once")]
+    check: bool,
+
+    /// Print a compact status-bar line instead of starting the TUI
+    #[arg(long, value_enum, conflicts_with_all = ["json", "prometheus", "once", "export"])]
+    format: Option<export::OutputFormat>,
+
+    /// Markup used by --format [default: ansi]
+    #[arg(long, value_enum, requires = "format")]
+    markup: Option<export::Markup>,
+
+    /// Render the overview once into an SVG file instead of starting the TUI
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["json", "prometheus", "once"])]
+    export: Option<PathBuf>,
+
+    /// Keep printing a sample every interval, as a single refreshing line without --json
+    #[arg(long, conflicts_with_all = ["prometheus", "once", "export"])]
+    watch: bool,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum Tab {
-    Perf,
+    Overview,
+    Process,
+    Network,
+    Disk,
     Clock,
 }
 
+const TABS: [Tab; 5] = [
+    Tab::Overview,
+    Tab::Process,
+    Tab::Network,
+    Tab::Disk,
+    Tab::Clock,
+];
+
 const CLOCK_COLORS: [Color; 16] = [
     Color::Black,
     Color::Red,
@@ -55,124 +285,802 @@ const CLOCK_COLORS: [Color; 16] = [
     Color::Gray,
 ];
 
+#[derive(Clone, Copy, PartialEq)]
+enum Panel {
+    Watched,
+    Cpu,
+    Memory,
+    Swap,
+    Network,
+    Battery,
+    Gpu,
+    Disk,
+    Numa,
+    Sensors,
+    Kernel,
+}
+
+const PANELS: [Panel; 11] = [
+    Panel::Watched,
+    Panel::Cpu,
+    Panel::Memory,
+    Panel::Swap,
+    Panel::Network,
+    Panel::Battery,
+    Panel::Gpu,
+    Panel::Disk,
+    Panel::Numa,
+    Panel::Sensors,
+    Panel::Kernel,
+];
+const TOGGLE_PANELS: [Panel; 4] = [Panel::Cpu, Panel::Memory, Panel::Swap, Panel::Network];
+
+impl Panel {
+    fn enabled(&self, app: &App) -> bool {
+        match self {
+            Panel::Watched => app.watch_pid.is_some(),
+            Panel::Cpu => app.panels.cpu && app.watch_pid.is_none(),
+            Panel::Memory => app.panels.memory,
+            Panel::Swap => app.panels.swap && app.metrics.total_swap > 0,
+            Panel::Network => app.panels.network,
+            Panel::Battery => app.panels.battery && app.metrics.battery.is_some(),
+            Panel::Gpu => app.panels.gpu && app.metrics.gpu.is_some(),
+            Panel::Disk => app.panels.disk,
+            Panel::Numa => app.panels.numa && app.metrics.numa_nodes.len() > 1,
+            Panel::Sensors => app.panels.sensors && sensor_count(app) > 0,
+            Panel::Kernel => app.panels.kernel && app.metrics.kernel_rates.is_some(),
+        }
+    }
+
+    fn vertical(&self) -> bool {
+        matches!(self, Panel::Cpu | Panel::Memory | Panel::Swap)
+    }
+
+    fn height(&self, app: &App) -> u16 {
+        match self {
+            Panel::Cpu if app.per_core => {
+                let (rows, _) = core_grid_shape(app.metrics.cpu_usages.len());
+                rows as u16 + 4
+            }
+            Panel::Cpu => 5,
+            Panel::Watched => 4,
+            Panel::Network => 10,
+            Panel::Gpu => 6,
+            Panel::Numa => app.metrics.numa_nodes.len() as u16 + 2,
+            Panel::Sensors => sensor_count(app) as u16 + 3,
+            Panel::Memory | Panel::Swap | Panel::Battery | Panel::Disk | Panel::Kernel => 3,
+        }
+    }
+}
+
 impl Tab {
     fn name(&self) -> &str {
         match self {
-            Tab::Perf => "perf(1)",
-            Tab::Clock => "clock(2)",
+            Tab::Overview => "Overview",
+            Tab::Process => "Processes",
+            Tab::Network => "Network",
+            Tab::Disk => "Disk",
+            Tab::Clock => "Clock",
+        }
+    }
+
+    fn index(&self) -> usize {
+        TABS.iter().position(|tab| tab == self).unwrap_or(0)
+    }
+
+    fn next(&self) -> Self {
+        TABS[(self.index() + 1) % TABS.len()]
+    }
+
+    fn prev(&self) -> Self {
+        TABS[(self.index() + TABS.len() - 1) % TABS.len()]
+    }
+}
+
+fn tab_bar_width() -> u16 {
+    TABS.iter()
+        .map(|tab| tab.name().len() as u16 + 3)
+        .sum::<u16>()
+        - 1
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum StatusClock {
+    Time24,
+    Time12,
+    DateTime,
+}
+
+impl StatusClock {
+    fn next(&self) -> Self {
+        match self {
+            StatusClock::Time24 => StatusClock::Time12,
+            StatusClock::Time12 => StatusClock::DateTime,
+            StatusClock::DateTime => StatusClock::Time24,
         }
     }
+}
+
+#[derive(Clone, Copy)]
+enum LabelMode {
+    Absolute,
+    Percent,
+}
 
+impl LabelMode {
     fn next(&self) -> Self {
         match self {
-            Tab::Perf => Tab::Clock,
-            Tab::Clock => Tab::Perf,
+            LabelMode::Absolute => LabelMode::Percent,
+            LabelMode::Percent => LabelMode::Absolute,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Trend {
+    Up,
+    Down,
+    Flat,
+}
+
+impl Trend {
+    fn between(previous: f64, current: f64) -> Self {
+        match current - previous {
+            delta if delta > TREND_TOLERANCE => Trend::Up,
+            delta if delta < -TREND_TOLERANCE => Trend::Down,
+            _ => Trend::Flat,
+        }
+    }
+
+    fn symbol(&self) -> &str {
+        match self {
+            Trend::Up => "▲",
+            Trend::Down => "▼",
+            Trend::Flat => "▬",
         }
     }
 }
 
 struct App {
-    system: System,
-    networks: Networks,
-    cpu_usage: f64,
-    memory_percent: f64,
-    swap_percent: f64,
-    used_memory_gb: f64,
-    used_swap_gb: f64,
-    network_upload_kbps: f64,
-    network_download_kbps: f64,
-    prev_network_received: u64,
-    prev_network_transmitted: u64,
+    metrics: Metrics,
+    worker: Worker,
+    fahrenheit: bool,
+    per_core: bool,
+    vertical: bool,
+    busiest_core: Option<usize>,
+    thresholds: [f64; 3],
+    severity_labels: bool,
+    labels_flipped: bool,
+    mem_label: Option<String>,
+    load_window: Option<LoadWindow>,
+    alerts: Alerts,
+    cpu_alert: bool,
+    memory_alert: bool,
+    oom_warning: bool,
+    ring_bell: bool,
+    frame_count: u64,
+    smooth: bool,
+    shown_cpu: f64,
+    shown_memory: f64,
+    memory_segments: [f64; 2],
+    shown_swap: f64,
+    shown_memory_segments: [f64; 2],
+    cpu_trend: Trend,
+    memory_trend: Trend,
+    swap_trend: Trend,
+    cpu_stats: Stats,
+    memory_stats: Stats,
+    swap_stats: Stats,
+    panels: Panels,
+    theme: Theme,
+    theme_name: ThemeName,
+    layout: LayoutMode,
+    padding: u16,
+    si: bool,
+    bits: bool,
+    show_interfaces: bool,
     refresh_interval: Duration,
+    history: Duration,
+    tick: Duration,
     last_update: Instant,
-    load_avg_1: f64,
-    load_avg_5: f64,
-    load_avg_15: f64,
-    cpu_history: Vec<u64>,
+    cpu_history: VecDeque<f64>,
+    cpu_history_times: VecDeque<DateTime<Local>>,
+    cpu_peak: Option<usize>,
+    cpu_trough: Option<usize>,
+    show_extremes: bool,
     terminal_width: u16,
     current_tab: Tab,
-    os_name: String,
-    kernel_version: String,
-    uptime_days: u64,
+    host_info: String,
+    boot_time: String,
     clock_color_index: usize,
+    clock_color_mode: ClockColorMode,
+    clock_font: ClockFont,
+    clock_scale: bool,
+    twelve_hour: bool,
+    clock_date_format: String,
+    clock_leading_zero: bool,
+    time_format: String,
+    status_seconds: bool,
+    status_clock: StatusClock,
+    fullscreen_clock: bool,
+    no_color: bool,
+    idle_timeout: Option<Duration>,
+    last_key: Instant,
+    dirty: bool,
+    drawn_second: i64,
+    stopwatch: Stopwatch,
+    show_stopwatch: bool,
+    process_view: ProcessView,
+    watch_pid: Option<Pid>,
+    watched: Option<ProcessInfo>,
+    exit_with_process: bool,
+    pending_kill: Option<PendingKill>,
+    message: Option<String>,
+    show_help: bool,
+    tab_area: Rect,
+    panel_areas: Vec<(Panel, Rect)>,
+    expanded_panel: Option<Panel>,
+    paused: bool,
+    manual: bool,
+    confirm_quit: bool,
+    quit_requested_at: Option<Instant>,
+    log: Option<File>,
+    output: Option<export::Output>,
+}
+
+const MAX_CORE_ROWS: usize = 16;
+const VERTICAL_BARS_HEIGHT: u16 = 12;
+const HELP_KEYS: [(&str, &str); 28] = [
+    ("q Esc", "quit"),
+    ("Tab S-Tab", "next / previous tab"),
+    ("1-5", "jump to tab"),
+    ("6-9", "toggle CPU / memory / swap / network"),
+    ("space", "pause / resume"),
+    ("r", "refresh now"),
+    ("p", "toggle process view"),
+    ("i", "toggle per-interface network"),
+    ("c", "toggle full-screen clock"),
+    ("R", "reset min / max statistics"),
+    ("d", "cycle status clock format"),
+    ("t", "cycle color theme"),
+    ("a", "toggle percent / absolute labels"),
+    ("m", "mark CPU history peak / trough"),
+    ("+ -", "faster / slower refresh"),
+    ("↑ ↓", "select process"),
+    ("k K", "terminate / kill process"),
+    ("s", "cycle process sort"),
+    ("/", "filter processes by name"),
+    ("← →", "change clock color"),
+    ("g", "cycle clock color mode"),
+    ("w", "toggle stopwatch"),
+    ("Enter", "start / stop stopwatch"),
+    ("z", "reset stopwatch"),
+    ("click", "expand / collapse panel"),
+    ("?", "show this help"),
+    ("", ""),
+    ("", "press any key to close"),
+];
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+const MAX_REFRESH_INTERVAL: Duration = Duration::from_secs(config::MAX_INTERVAL_SECS);
+const DEFAULT_CRITICAL_TEMPERATURE: f64 = 100.0;
+const MIN_TICK: Duration = Duration::from_millis(10);
+const MAX_TICK: Duration = Duration::from_secs(1);
+const SETTLED_DELTA: f64 = 0.05;
+const TREND_TOLERANCE: f64 = 0.5;
+const SMOOTHING_FACTOR: f64 = 0.3;
+const QUIT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn core_grid_shape(cores: usize) -> (usize, usize) {
+    let columns = cores.div_ceil(MAX_CORE_ROWS).max(1);
+    let rows = cores.div_ceil(columns);
+    (rows, columns)
+}
+
+fn format_temperature(celsius: f64, fahrenheit: bool) -> String {
+    if fahrenheit {
+        format!("{:.0}°F", celsius * 9.0 / 5.0 + 32.0)
+    } else {
+        format!("{celsius:.0}°C")
+    }
+}
+
+fn sensor_count(app: &App) -> usize {
+    app.metrics
+        .sensors
+        .iter()
+        .filter(|sensor| sensor.temperature.is_some())
+        .count()
+}
+
+fn format_uptime(seconds: u64) -> String {
+    format!(
+        "{}d {:02}:{:02}:{:02}",
+        seconds / 86400,
+        seconds % 86400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+fn format_rate(bytes_per_sec: f64) -> String {
+    match bytes_per_sec {
+        r if r < 1024.0 => format!("{r:.1} B/s"),
+        r if r < 1024.0 * 1024.0 => format!("{:.1} KB/s", r / 1024.0),
+        r => format!("{:.1} MB/s", r / 1024.0 / 1024.0),
+    }
+}
+
+fn alert_block<'a>(app: &App, title: impl Into<Line<'a>>, alerting: bool) -> Block<'a> {
+    let block = app.theme.block(title);
+    if alerting && app.frame_count.is_multiple_of(2) {
+        block.border_style(
+            Style::default()
+                .fg(app.theme.levels[3])
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        block
+    }
+}
+
+fn expand_label(template: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |label, (name, value)| {
+            label.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+fn gauge_label(app: &App, percentage: f64, absolute: String, native: LabelMode) -> String {
+    let mode = if app.labels_flipped {
+        native.next()
+    } else {
+        native
+    };
+    match mode {
+        LabelMode::Absolute => absolute,
+        LabelMode::Percent => format!("{percentage:.1}%"),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Severity {
+    Ok,
+    Warn,
+    Crit,
+}
+
+impl Severity {
+    fn of(percentage: f64, thresholds: &[f64; 3]) -> Self {
+        Severity::between(percentage, thresholds[1], thresholds[2])
+    }
+
+    fn between(percentage: f64, warn: f64, crit: f64) -> Self {
+        match percentage {
+            p if p < warn => Severity::Ok,
+            p if p < crit => Severity::Warn,
+            _ => Severity::Crit,
+        }
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            Severity::Ok => "OK",
+            Severity::Warn => "WARN",
+            Severity::Crit => "CRIT",
+        }
+    }
+}
+
+fn severity_label(app: &App, percentage: f64, label: String) -> String {
+    if !app.severity_labels {
+        return label;
+    }
+    format!(
+        "{label} {}",
+        Severity::of(percentage, &app.thresholds).label()
+    )
 }
 
-fn get_gauge_color(percentage: f64) -> Color {
+fn get_gauge_color(percentage: f64, thresholds: &[f64; 3], theme: &Theme) -> Color {
     match percentage {
-        p if p < 25.0 => Color::Blue,
-        p if p < 50.0 => Color::Cyan,
-        p if p < 75.0 => Color::Yellow,
-        _ => Color::Red,
+        p if p < thresholds[0] => theme.levels[0],
+        p if p < thresholds[1] => theme.levels[1],
+        p if p < thresholds[2] => theme.levels[2],
+        _ => theme.levels[3],
+    }
+}
+
+fn load_color(load: f64, cores: usize, theme: &Theme) -> Color {
+    let cores = cores.max(1) as f64;
+    match load {
+        l if l < 0.7 * cores => theme.levels[1],
+        l if l < cores => theme.levels[2],
+        _ => theme.levels[3],
     }
 }
 
 impl App {
-    fn new(refresh_interval: Duration) -> App {
-        let mut system = System::new_all();
-        system.refresh_all();
-        let networks = Networks::new_with_refreshed_list();
-
-        let cpu_usage = system.global_cpu_usage() as f64;
-        let used_memory_gb = system.used_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
-        let memory_percent = (system.used_memory() as f64 / system.total_memory() as f64) * 100.0;
-        let swap_percent = (system.used_swap() as f64 / system.total_swap() as f64) * 100.0;
-        let used_swap_gb = system.used_swap() as f64 / 1024.0 / 1024.0 / 1024.0;
-
-        // Get initial network stats
-        let (total_received, total_transmitted) =
-            networks.iter().fold((0, 0), |(rx, tx), (_, data)| {
-                (rx + data.total_received(), tx + data.total_transmitted())
+    fn new(config: &Config) -> App {
+        let sampler = Sampler::new(config.memory_available, config.all_interfaces)
+            .with_interface_filter(InterfaceFilter {
+                include: config.iface.clone(),
+                exclude: config.exclude_iface.clone(),
             });
+        let metrics = sampler.metrics.clone();
+        let worker = Worker::spawn(sampler);
+        let theme = config.theme.theme();
+        let clock_color_index = CLOCK_COLORS
+            .iter()
+            .position(|&color| color == theme.text)
+            .unwrap_or(15);
 
-        let load_avg = System::load_average();
-
+        let host_name = System::host_name().unwrap_or_else(|| "Unknown".to_string());
         let os_name = System::name().unwrap_or_else(|| "Unknown".to_string());
+        let os_version = System::os_version().unwrap_or_default();
         let kernel_version = System::kernel_version().unwrap_or_else(|| "Unknown".to_string());
-        let uptime_days = System::uptime() / 3600 / 24;
+        let host_info = format!("{host_name} | {os_name} {os_version} | Kernel {kernel_version}");
+        let boot_time = DateTime::from_timestamp(System::boot_time() as i64, 0)
+            .map(|time| {
+                time.with_timezone(&Local)
+                    .format(&config.time_format)
+                    .to_string()
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
 
-        App {
-            system,
-            networks,
-            cpu_usage,
-            memory_percent,
-            swap_percent,
-            used_memory_gb,
-            used_swap_gb,
-            network_upload_kbps: 0.0,
-            network_download_kbps: 0.0,
-            prev_network_received: total_received,
-            prev_network_transmitted: total_transmitted,
-            refresh_interval,
+        let mut app = App {
+            fahrenheit: config.fahrenheit,
+            per_core: config.per_core,
+            vertical: config.vertical,
+            busiest_core: None,
+            thresholds: config.thresholds,
+            severity_labels: config.severity_labels,
+            labels_flipped: false,
+            mem_label: config.mem_label.clone(),
+            load_window: config.load_window,
+            alerts: config.alerts,
+            cpu_alert: false,
+            memory_alert: false,
+            oom_warning: false,
+            ring_bell: false,
+            frame_count: 0,
+            smooth: config.smooth,
+            shown_cpu: metrics.cpu_usage,
+            shown_memory: metrics.memory_percent,
+            memory_segments: [0.0, 0.0],
+            shown_swap: metrics.swap_percent,
+            shown_memory_segments: [0.0, 0.0],
+            cpu_trend: Trend::Flat,
+            memory_trend: Trend::Flat,
+            swap_trend: Trend::Flat,
+            cpu_stats: Stats::new(),
+            memory_stats: Stats::new(),
+            swap_stats: Stats::new(),
+            panels: config.panels,
+            theme,
+            theme_name: config.theme,
+            layout: config.layout,
+            padding: config.padding,
+            si: config.si,
+            bits: config.bits,
+            show_interfaces: false,
+            refresh_interval: Duration::from_secs_f64(config.interval),
+            history: Duration::from_secs(config.history),
+            tick: Duration::from_millis(config.tick).clamp(MIN_TICK, MAX_TICK),
             last_update: Instant::now(),
-            load_avg_1: load_avg.one,
-            load_avg_5: load_avg.five,
-            load_avg_15: load_avg.fifteen,
-            cpu_history: vec![0; 200],
+            cpu_history: VecDeque::from(vec![0.0; 200]),
+            cpu_history_times: VecDeque::new(),
+            cpu_peak: None,
+            cpu_trough: None,
+            show_extremes: false,
             terminal_width: 0,
-            current_tab: Tab::Perf,
-            os_name,
-            kernel_version,
-            uptime_days,
-            clock_color_index: 15,
-        }
+            current_tab: Tab::Overview,
+            host_info,
+            boot_time,
+            clock_color_index,
+            clock_color_mode: config.clock_color,
+            clock_font: config.clock_font,
+            clock_scale: config.clock_scale,
+            twelve_hour: config.twelve_hour,
+            clock_date_format: config.clock_date_format.clone(),
+            clock_leading_zero: config.clock_leading_zero,
+            time_format: config.time_format.clone(),
+            status_seconds: config.status_seconds,
+            status_clock: if config.twelve_hour {
+                StatusClock::Time12
+            } else {
+                StatusClock::Time24
+            },
+            fullscreen_clock: false,
+            no_color: false,
+            idle_timeout: (config.idle_timeout > 0)
+                .then(|| Duration::from_secs(config.idle_timeout)),
+            last_key: Instant::now(),
+            dirty: true,
+            drawn_second: 0,
+            stopwatch: Stopwatch::new(),
+            show_stopwatch: false,
+            process_view: ProcessView::new(),
+            watch_pid: None,
+            watched: None,
+            exit_with_process: false,
+            pending_kill: None,
+            message: None,
+            show_help: false,
+            tab_area: Rect::default(),
+            panel_areas: Vec::new(),
+            expanded_panel: None,
+            paused: false,
+            manual: false,
+            confirm_quit: config.confirm_quit,
+            quit_requested_at: None,
+            log: None,
+            output: None,
+            metrics,
+            worker,
+        };
+        app.set_refresh_interval(Duration::from_secs_f64(config.interval));
+        app
     }
 
     fn update(&mut self) {
+        if let Some((request, metrics)) = self.worker.try_recv() {
+            self.apply_sample(request, metrics);
+        }
+        if self.paused || self.manual {
+            return;
+        }
         self.update_system_stats();
     }
 
+    fn step(&mut self) {
+        if self.worker.request(self.sample_request()) {
+            self.last_update = Instant::now();
+        }
+    }
+
+    fn animate(&mut self) {
+        if self.smooth {
+            let ease = |shown: f64, target: f64| shown + (target - shown) * SMOOTHING_FACTOR;
+            self.shown_cpu = ease(self.shown_cpu, self.metrics.cpu_usage);
+            self.shown_memory = ease(self.shown_memory, self.metrics.memory_percent);
+            self.shown_swap = ease(self.shown_swap, self.metrics.swap_percent);
+            for (shown, target) in self
+                .shown_memory_segments
+                .iter_mut()
+                .zip(self.memory_segments)
+            {
+                *shown = ease(*shown, target);
+            }
+            let settling = |shown: f64, target: f64| (target - shown).abs() > SETTLED_DELTA;
+            if settling(self.shown_cpu, self.metrics.cpu_usage)
+                || settling(self.shown_memory, self.metrics.memory_percent)
+                || settling(self.shown_swap, self.metrics.swap_percent)
+                || self
+                    .shown_memory_segments
+                    .iter()
+                    .zip(self.memory_segments)
+                    .any(|(&shown, target)| settling(shown, target))
+            {
+                self.dirty = true;
+            }
+        } else {
+            self.shown_cpu = self.metrics.cpu_usage;
+            self.shown_memory = self.metrics.memory_percent;
+            self.shown_swap = self.metrics.swap_percent;
+            self.shown_memory_segments = self.memory_segments;
+        }
+    }
+
+    fn request_quit(&mut self) -> bool {
+        if !self.confirm_quit || self.quit_pending() {
+            return true;
+        }
+        self.quit_requested_at = Some(Instant::now());
+        false
+    }
+
+    fn quit_pending(&self) -> bool {
+        self.quit_requested_at
+            .is_some_and(|at| at.elapsed() < QUIT_CONFIRM_TIMEOUT)
+    }
+
+    fn faster_refresh(&mut self) {
+        self.set_refresh_interval(self.refresh_interval / 2);
+    }
+
+    fn slower_refresh(&mut self) {
+        self.set_refresh_interval(self.refresh_interval * 2);
+    }
+
+    fn set_refresh_interval(&mut self, interval: Duration) {
+        self.refresh_interval = interval.clamp(MIN_REFRESH_INTERVAL, MAX_REFRESH_INTERVAL);
+        let samples = self.history.as_secs_f64() / self.refresh_interval.as_secs_f64();
+        self.metrics.set_history_len(samples.round() as usize);
+        self.worker.set_history_len(samples.round() as usize);
+    }
+
+    fn click(&mut self, position: Position) {
+        if self.fullscreen_clock {
+            return;
+        }
+        if self.tab_area.contains(position) {
+            let mut x = self.tab_area.x;
+            for tab in TABS {
+                x += tab.name().len() as u16 + 3;
+                if position.x < x {
+                    self.current_tab = tab;
+                    break;
+                }
+            }
+            return;
+        }
+        if self.current_tab != Tab::Overview {
+            return;
+        }
+        self.expanded_panel = match self.expanded_panel {
+            Some(_) => None,
+            None => self
+                .panel_areas
+                .iter()
+                .find(|(_, area)| area.contains(position))
+                .map(|&(panel, _)| panel),
+        };
+    }
+
+    fn toggle_fullscreen_clock(&mut self) {
+        self.fullscreen_clock = !self.fullscreen_clock;
+    }
+
+    fn network_rate(&self, bytes_per_sec: f64) -> String {
+        if self.bits {
+            format_bit_rate(bytes_per_sec)
+        } else {
+            format_rate(bytes_per_sec)
+        }
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+            || Local::now().timestamp() != self.drawn_second
+            || (self.showing_clock()
+                && (self.clock_color_mode == ClockColorMode::Cycle || self.stopwatch.running()))
+    }
+
+    fn is_idle(&self) -> bool {
+        self.idle_timeout
+            .is_some_and(|timeout| self.last_key.elapsed() >= timeout)
+    }
+
+    fn network_reading(&self, bytes_per_sec: f64, reset: bool) -> String {
+        if reset {
+            "—".to_string()
+        } else {
+            self.network_rate(bytes_per_sec)
+        }
+    }
+
+    fn showing_clock(&self) -> bool {
+        self.fullscreen_clock || self.current_tab == Tab::Clock
+    }
+
+    fn toggle_stopwatch(&mut self) {
+        self.show_stopwatch = !self.show_stopwatch;
+    }
+
+    fn cycle_status_clock(&mut self) {
+        self.status_clock = self.status_clock.next();
+    }
+
+    fn status_time(&self) -> String {
+        let format = match (self.status_clock, self.status_seconds) {
+            (StatusClock::Time24, true) => "%H:%M:%S",
+            (StatusClock::Time24, false) => "%H:%M",
+            (StatusClock::Time12, true) => "%I:%M:%S %p",
+            (StatusClock::Time12, false) => "%I:%M %p",
+            (StatusClock::DateTime, _) => &self.time_format,
+        };
+        Local::now().format(format).to_string()
+    }
+
+    fn reset_stats(&mut self) {
+        self.cpu_stats = Stats::new();
+        self.memory_stats = Stats::new();
+        self.swap_stats = Stats::new();
+        self.message = Some("Statistics reset".to_string());
+    }
+
+    fn toggle_interfaces(&mut self) {
+        self.show_interfaces = !self.show_interfaces;
+    }
+
+    fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.worker.reset_baseline();
+            self.last_update = Instant::now();
+        }
+    }
+
+    fn record_cpu_history(&mut self) {
+        self.cpu_history.push_front(self.metrics.cpu_usage);
+        self.cpu_history_times.push_front(Local::now());
+    }
+
+    fn trim_cpu_history(&mut self) {
+        let len = self.terminal_width as usize * 2;
+        self.cpu_history.truncate(len);
+        self.cpu_history_times.truncate(len);
+        let sampled = || {
+            self.cpu_history
+                .iter()
+                .take(self.cpu_history_times.len())
+                .enumerate()
+        };
+        self.cpu_peak = sampled()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index);
+        self.cpu_trough = sampled()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index);
+    }
+
+    fn toggle_panel(&mut self, panel: Panel) {
+        let enabled = match panel {
+            Panel::Cpu => &mut self.panels.cpu,
+            Panel::Memory => &mut self.panels.memory,
+            Panel::Swap => &mut self.panels.swap,
+            Panel::Network => &mut self.panels.network,
+            _ => return,
+        };
+        *enabled = !*enabled;
+        if self.expanded_panel == Some(panel) {
+            self.expanded_panel = None;
+        }
+    }
+
+    fn toggle_extremes(&mut self) {
+        self.show_extremes = !self.show_extremes;
+    }
+
     fn set_terminal_width(&mut self, width: u16) {
         self.terminal_width = width;
-        let max_points = self.terminal_width as usize;
-        while self.cpu_history.len() > max_points {
-            self.cpu_history.pop();
-        }
+        self.trim_cpu_history();
     }
 
     fn switch_tab(&mut self) {
         self.current_tab = self.current_tab.next();
     }
 
+    fn switch_tab_back(&mut self) {
+        self.current_tab = self.current_tab.prev();
+    }
+
+    fn toggle_process_view(&mut self) {
+        self.current_tab = if self.current_tab == Tab::Process {
+            Tab::Overview
+        } else {
+            Tab::Process
+        };
+    }
+
+    fn request_kill(&mut self, signal: Signal) {
+        if let Some(process) = self.process_view.selected() {
+            self.pending_kill = Some(PendingKill {
+                pid: process.pid,
+                name: process.name.clone(),
+                start_time: process.start_time,
+                signal,
+            });
+        }
+    }
+
+    fn confirm_kill(&mut self) {
+        if let Some(kill) = self.pending_kill.take() {
+            self.message = Some(process::kill_process(&kill));
+        }
+    }
+
     fn next_clock_color(&mut self) {
         self.clock_color_index = (self.clock_color_index + 1) % CLOCK_COLORS.len();
     }
@@ -181,91 +1089,362 @@ impl App {
         self.clock_color_index = self.clock_color_index.saturating_sub(1) % CLOCK_COLORS.len();
     }
 
-    fn clock_color(&self) -> Color {
-        CLOCK_COLORS[self.clock_color_index]
+    fn toggle_label_mode(&mut self) {
+        self.labels_flipped = !self.labels_flipped;
+    }
+
+    fn cycle_theme(&mut self) {
+        self.theme_name = self.theme_name.next();
+        self.theme = self.theme_name.theme();
+    }
+
+    fn cycle_clock_color_mode(&mut self) {
+        self.clock_color_mode = self.clock_color_mode.next();
+    }
+
+    fn clock_color(&self) -> ClockColor {
+        match self.clock_color_mode {
+            ClockColorMode::Fixed => ClockColor::Fixed(CLOCK_COLORS[self.clock_color_index]),
+            ClockColorMode::Cycle => ClockColor::Cycle,
+            ClockColorMode::Load => ClockColor::Load(self.metrics.cpu_usage),
+        }
     }
 
     fn update_system_stats(&mut self) {
-        if self.last_update.elapsed() >= self.refresh_interval {
-            if self.current_tab == Tab::Perf {
-                self.system.refresh_all();
-                self.networks.refresh(true);
-
-                let elapsed_secs = self.last_update.elapsed().as_secs_f64();
-
-                self.cpu_usage = self.system.global_cpu_usage() as f64;
-                self.used_memory_gb = self.system.used_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
-                self.memory_percent =
-                    (self.system.used_memory() as f64 / self.system.total_memory() as f64) * 100.0;
-                self.swap_percent =
-                    (self.system.used_swap() as f64 / self.system.total_swap() as f64) * 100.0;
-                self.used_swap_gb = self.system.used_swap() as f64 / 1024.0 / 1024.0 / 1024.0;
-
-                let (total_received, total_transmitted) =
-                    self.networks.iter().fold((0, 0), |(rx, tx), (_, data)| {
-                        (rx + data.total_received(), tx + data.total_transmitted())
-                    });
-
-                let bytes_received = total_received.saturating_sub(self.prev_network_received);
-                let bytes_transmitted =
-                    total_transmitted.saturating_sub(self.prev_network_transmitted);
-
-                self.network_download_kbps = (bytes_received as f64 / elapsed_secs) / 1024.0;
-                self.network_upload_kbps = (bytes_transmitted as f64 / elapsed_secs) / 1024.0;
-
-                self.prev_network_received = total_received;
-                self.prev_network_transmitted = total_transmitted;
-
-                let load_avg = System::load_average();
-                self.load_avg_1 = load_avg.one;
-                self.load_avg_5 = load_avg.five;
-                self.load_avg_15 = load_avg.fifteen;
-
-                self.cpu_history.insert(0, self.cpu_usage as u64);
-            } else {
-                self.system.refresh_cpu_all();
-                self.cpu_usage = self.system.global_cpu_usage() as f64;
-                self.cpu_history.insert(0, self.cpu_usage as u64);
-            }
+        let now = Instant::now();
+        if now.duration_since(self.last_update) >= self.refresh_interval
+            && self.worker.request(self.sample_request())
+        {
+            self.last_update = now;
+        }
+    }
 
-            let max_points = self.terminal_width as usize;
-            while self.cpu_history.len() > max_points {
-                self.cpu_history.pop();
-            }
+    fn sample(&mut self) {
+        self.worker
+            .request(Request::Full(self.refresh_interval / 2, Scope::ALL));
+        if let Some((request, metrics)) = self.worker.recv() {
+            self.apply_sample(request, metrics);
+        }
+        self.last_update = Instant::now();
+    }
 
-            self.last_update = Instant::now();
+    fn sample_request(&self) -> Request {
+        if self.showing_clock() {
+            Request::Cpu
+        } else {
+            Request::Full(self.refresh_interval / 2, self.sample_scope())
+        }
+    }
+
+    fn sample_scope(&self) -> Scope {
+        let overview = |panel: Panel| self.current_tab == Tab::Overview && panel.enabled(self);
+        Scope {
+            processes: self.watch_pid.is_some()
+                || matches!(self.current_tab, Tab::Process | Tab::Disk)
+                || overview(Panel::Disk),
+            network: self.current_tab == Tab::Network
+                || overview(Panel::Network)
+                || self.log.is_some()
+                || self.output.is_some(),
+            sensors: overview(Panel::Cpu) || overview(Panel::Sensors),
+            disks: self.current_tab == Tab::Disk,
+        }
+    }
+
+    fn apply_sample(&mut self, request: Request, metrics: Metrics) {
+        match request {
+            Request::Full(..) => {
+                let previous = mem::replace(&mut self.metrics, metrics);
+                self.cpu_trend = Trend::between(previous.cpu_usage, self.metrics.cpu_usage);
+                self.memory_trend =
+                    Trend::between(previous.memory_percent, self.metrics.memory_percent);
+                self.swap_trend = Trend::between(previous.swap_percent, self.metrics.swap_percent);
+
+                self.cpu_stats.record(self.metrics.cpu_usage);
+                self.memory_stats.record(self.metrics.memory_percent);
+                self.swap_stats.record(self.metrics.swap_percent);
+
+                let cpu_alert = self.metrics.cpu_usage >= self.alerts.cpu;
+                let memory_alert = self.metrics.memory_percent >= self.alerts.memory;
+                if self.alerts.bell
+                    && ((cpu_alert && !self.cpu_alert) || (memory_alert && !self.memory_alert))
+                {
+                    self.ring_bell = true;
+                }
+                self.cpu_alert = cpu_alert;
+                self.memory_alert = memory_alert;
+
+                self.memory_segments = if self.metrics.total_memory == 0 {
+                    [0.0, 0.0]
+                } else {
+                    let total = self.metrics.total_memory as f64;
+                    let used = self
+                        .metrics
+                        .total_memory
+                        .saturating_sub(self.metrics.available_memory);
+                    let cache = self
+                        .metrics
+                        .available_memory
+                        .min(self.metrics.cached_memory);
+                    [used as f64 / total * 100.0, cache as f64 / total * 100.0]
+                };
+
+                let available_percent = if self.metrics.total_memory == 0 {
+                    100.0
+                } else {
+                    self.metrics.available_memory as f64 / self.metrics.total_memory as f64 * 100.0
+                };
+                self.oom_warning = available_percent < self.alerts.oom_available
+                    && (self.metrics.total_swap == 0
+                        || self.metrics.swap_percent >= self.alerts.oom_swap);
+
+                self.record_cpu_history();
+                self.busiest_core = self
+                    .metrics
+                    .cpu_usages
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(index, _)| index);
+
+                self.process_view.refresh(&self.metrics.processes);
+                if let Some(pid) = self.watch_pid {
+                    self.watched = self.metrics.process(pid).cloned();
+                }
+
+                if let Some(mut log) = self.log.take() {
+                    match export::write_log(&mut log, self) {
+                        Ok(()) => self.log = Some(log),
+                        Err(err) => self.message = Some(format!("Logging stopped: {err}")),
+                    }
+                }
+                export::write_output(self);
+            }
+            Request::Cpu => {
+                self.metrics = metrics;
+                self.record_cpu_history();
+            }
         }
+
+        self.trim_cpu_history();
+        self.dirty = true;
     }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
 
+    let mut config = Config::load()?;
+    if let Some(interval) = args.interval {
+        config.interval = interval;
+    }
+    if let Some(history) = args.history {
+        config.history = history;
+    }
+    if let Some(tick) = args.tick {
+        config.tick = tick;
+    }
+    if args.per_core {
+        config.per_core = true;
+    }
+    if args.vertical {
+        config.vertical = true;
+    }
+    if args.memory_available {
+        config.memory_available = true;
+    }
+    if args.twelve_hour {
+        config.twelve_hour = true;
+    }
+    if args.no_leading_zero {
+        config.clock_leading_zero = false;
+    }
+    if args.no_seconds {
+        config.status_seconds = false;
+    }
+    if args.no_mouse {
+        config.mouse = false;
+    }
+    if args.si {
+        config.si = true;
+    }
+    if args.bits {
+        config.bits = true;
+    }
+    if args.fahrenheit {
+        config.fahrenheit = true;
+    }
+    if args.confirm_quit {
+        config.confirm_quit = true;
+    }
+    if args.smooth {
+        config.smooth = true;
+    }
+    if let Some(idle_timeout) = args.idle_timeout {
+        config.idle_timeout = idle_timeout;
+    }
+    if args.all_interfaces {
+        config.all_interfaces = true;
+    }
+    if let Some(iface) = args.iface {
+        config.iface = iface;
+    }
+    if let Some(exclude_iface) = args.exclude_iface {
+        config.exclude_iface = exclude_iface;
+    }
+    if let Some(time_format) = args.time_format {
+        config.time_format = time_format;
+    }
+    if let Some(clock_date_format) = args.clock_date_format {
+        config.clock_date_format = clock_date_format;
+    }
+    if let Some(thresholds) = args.thresholds {
+        config.thresholds = thresholds;
+    }
+    if args.severity_labels {
+        config.severity_labels = true;
+    }
+    if let Some(mem_label) = args.mem_label {
+        config.mem_label = Some(mem_label);
+    }
+    if let Some(load_window) = args.load_window {
+        config.load_window = Some(load_window);
+    }
+    if let Some(clock_color) = args.clock_color {
+        config.clock_color = clock_color;
+    }
+    if let Some(clock_font) = args.clock_font {
+        config.clock_font = clock_font;
+    }
+    if args.clock_scale {
+        config.clock_scale = true;
+    }
+    let state = State::load();
+    if let Some(state) = &state {
+        config.theme = state.theme;
+    }
+    if let Some(theme) = args.theme {
+        config.theme = theme;
+    }
+    if let Some(layout) = args.layout {
+        config.layout = layout;
+    }
+    if let Some(padding) = args.padding {
+        config.padding = padding;
+    }
+
+    config::validate_time_format(&config.time_format)?;
+    config::validate_time_format(&config.clock_date_format)?;
+    config::validate_thresholds(&config.thresholds)?;
+    config::validate_interval(config.interval)?;
+    config::validate_history(config.history)?;
+    config::validate_padding(config.padding)?;
+
+    // Create app
+    let mut app = App::new(&config);
+    app.fullscreen_clock = args.clock;
+    app.manual = args.manual;
+    app.watched = match (args.pid, &args.name) {
+        (Some(pid), _) => app.metrics.process(Pid::from_u32(pid)).cloned(),
+        (None, Some(name)) => Some(
+            app.metrics
+                .find_process(name)
+                .cloned()
+                .ok_or_else(|| format!("no process matching '{name}'"))?,
+        ),
+        (None, None) => None,
+    };
+    app.watch_pid = app
+        .watched
+        .as_ref()
+        .map(|process| process.pid)
+        .or(args.pid.map(Pid::from_u32));
+    app.exit_with_process = args.exit_with_process;
+    app.no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    if let Some(path) = &args.log {
+        app.log = Some(export::open_log(path)?);
+    }
+    if let Some(path) = args.output {
+        app.output = Some(export::Output::open(path)?);
+    }
+
+    if args.json {
+        return export::run_json(&mut app, args.watch);
+    }
+    if let Some(port) = args.prometheus {
+        return export::run_prometheus(&mut app, port);
+    }
+    if let Some(path) = &args.export {
+        return export::run_export(&mut app, path);
+    }
+    if let Some(export::OutputFormat::Bar) = args.format {
+        let markup = args.markup.unwrap_or(export::Markup::Ansi);
+        return export::run_bar(&mut app, markup, args.watch);
+    }
+    if args.watch {
+        return export::run_watch(&mut app);
+    }
+    if args.check {
+        let code = export::run_check(&mut app)?;
+        std::process::exit(code);
+    }
+    if args.once || !io::stdout().is_terminal() {
+        return export::run_once(&mut app);
+    }
+
+    if let Some(state) = &state {
+        app.current_tab = state.tab;
+        app.process_view.sort_key = state.sort_key;
+        if args.interval.is_none() {
+            app.set_refresh_interval(Duration::from_secs_f64(state.interval));
+        }
+    }
+
     // Setup terminal
-    enable_raw_mode()?;
+    let mouse = config.mouse;
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        if mouse {
+            let _ = execute!(io::stdout(), DisableMouseCapture);
+        }
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, cursor::Show);
+        default_hook(info);
+    }));
+
+    if let Err(err) = enable_raw_mode() {
+        eprintln!("zemon: cannot set up the terminal ({err})");
+        eprintln!("Use --once for a text snapshot or --json for machine-readable output.");
+        std::process::exit(1);
+    }
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app
-    let mut app = App::new(Duration::from_secs(args.interval));
-
     // Run the app
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    if mouse {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
+    if let Some(output) = app.output.take() {
+        output.finish_within(export::OUTPUT_DRAIN_TIMEOUT);
+    }
     if let Err(err) = res {
         println!("{err:?}");
     }
+    if let Err(err) = State::from_app(&app).save() {
+        eprintln!("Failed to save state: {err}");
+    }
 
     Ok(())
 }
@@ -276,83 +1455,458 @@ where
 {
     loop {
         app.update();
-        terminal.draw(|f| ui(f, app))?;
+        if app.exit_with_process && app.watch_pid.is_some() && app.watched.is_none() {
+            return Ok(());
+        }
+        app.animate();
+        if app.needs_redraw() {
+            app.drawn_second = Local::now().timestamp();
+            terminal.draw(|f| ui(f, app))?;
+            app.dirty = false;
+        }
+        if app.ring_bell {
+            app.ring_bell = false;
+            execute!(io::stdout(), Print('\x07'))?;
+        }
 
-        if event::poll(Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
-        {
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => return Ok(()),
-                KeyCode::Tab => app.switch_tab(),
-                KeyCode::Left if app.current_tab == Tab::Clock => app.prev_clock_color(),
-                KeyCode::Right if app.current_tab == Tab::Clock => app.next_clock_color(),
+        if event::poll(app.tick)? {
+            match event::read()? {
+                Event::Key(key) => {
+                    let was_idle = app.is_idle();
+                    app.last_key = Instant::now();
+                    app.dirty = true;
+                    if !was_idle && handle_key(app, key) {
+                        return Ok(());
+                    }
+                }
+                Event::Resize(width, height) => {
+                    terminal.resize(Rect::new(0, 0, width, height))?;
+                    app.dirty = true;
+                }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    app.click(Position::new(mouse.column, mouse.row));
+                    app.dirty = true;
+                }
                 _ => {}
             }
         }
     }
 }
 
+fn handle_key(app: &mut App, key: KeyEvent) -> bool {
+    app.message = None;
+    if app.show_help {
+        app.show_help = false;
+        return false;
+    }
+    if app.process_view.editing_filter {
+        let view = &mut app.process_view;
+        match key.code {
+            KeyCode::Char(c) => view.filter.push(c),
+            KeyCode::Backspace => {
+                view.filter.pop();
+            }
+            KeyCode::Enter => view.editing_filter = false,
+            KeyCode::Esc => {
+                view.filter.clear();
+                view.editing_filter = false;
+            }
+            _ => {}
+        }
+        view.refresh(&app.metrics.processes);
+        return false;
+    }
+    if app.pending_kill.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm_kill(),
+            _ => app.pending_kill = None,
+        }
+        return false;
+    }
+
+    let quit_key = matches!(
+        key.code,
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc
+    );
+    if !quit_key {
+        app.quit_requested_at = None;
+    }
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc if app.request_quit() => {
+            return true;
+        }
+        KeyCode::Char('?') => app.show_help = true,
+        KeyCode::Tab => app.switch_tab(),
+        KeyCode::BackTab => app.switch_tab_back(),
+        KeyCode::Char(c @ '1'..='5') => app.current_tab = TABS[c as usize - '1' as usize],
+        KeyCode::Char(c @ '6'..='9') => app.toggle_panel(TOGGLE_PANELS[c as usize - '6' as usize]),
+        KeyCode::Char(' ') => app.toggle_pause(),
+        KeyCode::Char('p') => app.toggle_process_view(),
+        KeyCode::Char('i') => app.toggle_interfaces(),
+        KeyCode::Char('c') => app.toggle_fullscreen_clock(),
+        KeyCode::Char('R') => app.reset_stats(),
+        KeyCode::Char('d') => app.cycle_status_clock(),
+        KeyCode::Char('t') => app.cycle_theme(),
+        KeyCode::Char('a') => app.toggle_label_mode(),
+        KeyCode::Char('m') => app.toggle_extremes(),
+        KeyCode::Char('+') | KeyCode::Char('=') => app.faster_refresh(),
+        KeyCode::Char('-') => app.slower_refresh(),
+        KeyCode::Up if app.current_tab == Tab::Process => app.process_view.select_prev(),
+        KeyCode::Down if app.current_tab == Tab::Process => app.process_view.select_next(),
+        KeyCode::Char('/') if app.current_tab == Tab::Process => {
+            app.process_view.editing_filter = true
+        }
+        KeyCode::Char('s') if app.current_tab == Tab::Process => app.process_view.cycle_sort(),
+        KeyCode::Char('k') if app.current_tab == Tab::Process => app.request_kill(Signal::Term),
+        KeyCode::Char('K') if app.current_tab == Tab::Process => app.request_kill(Signal::Kill),
+        KeyCode::Left if app.showing_clock() => app.prev_clock_color(),
+        KeyCode::Right if app.showing_clock() => app.next_clock_color(),
+        KeyCode::Char('g') if app.showing_clock() => app.cycle_clock_color_mode(),
+        KeyCode::Char('w') if app.showing_clock() => app.toggle_stopwatch(),
+        KeyCode::Enter if app.showing_clock() && app.show_stopwatch => app.stopwatch.toggle(),
+        KeyCode::Char('z') if app.showing_clock() && app.show_stopwatch => app.stopwatch.reset(),
+        KeyCode::Char('r') => app.step(),
+        _ => {}
+    }
+    false
+}
+
 fn ui(f: &mut Frame, app: &mut App) {
+    render_screen(f, app);
+    if app.no_color {
+        strip_colors(f.buffer_mut());
+    } else if app.is_idle() {
+        dim_colors(f.buffer_mut());
+    }
+}
+
+fn dim_color(color: Color) -> Color {
+    match color {
+        Color::White => Color::Gray,
+        Color::Gray | Color::Reset => Color::DarkGray,
+        Color::LightRed => Color::Red,
+        Color::LightGreen => Color::Green,
+        Color::LightYellow => Color::Yellow,
+        Color::LightBlue => Color::Blue,
+        Color::LightMagenta => Color::Magenta,
+        Color::LightCyan => Color::Cyan,
+        Color::Rgb(r, g, b) => Color::Rgb(r / 2, g / 2, b / 2),
+        color => color,
+    }
+}
+
+fn dim_colors(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = dim_color(cell.fg);
+        if cell.bg != Color::Reset {
+            cell.bg = dim_color(cell.bg);
+        }
+        cell.modifier.insert(Modifier::DIM);
+    }
+}
+
+fn strip_colors(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if cell.bg != Color::Reset {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+fn render_screen(f: &mut Frame, app: &mut App) {
+    app.frame_count += 1;
     app.set_terminal_width(f.area().width);
 
+    if app.fullscreen_clock {
+        render_clock_tab(f, app, f.area());
+        if app.show_help {
+            render_help(f, app);
+        }
+        return;
+    }
+
+    let footer_height = if app.current_tab == Tab::Overview && app.panels.cpu {
+        0
+    } else {
+        3
+    };
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(if app.oom_warning { 1 } else { 0 }),
             Constraint::Min(0),
-            Constraint::Length(3),
+            Constraint::Length(footer_height),
         ])
         .split(f.area());
 
+    let header = Paragraph::new(format!(
+        "{} | {} processes, {} threads",
+        app.host_info, app.metrics.process_count, app.metrics.thread_count
+    ))
+    .alignment(Alignment::Center)
+    .style(Style::default().fg(app.theme.text));
+    f.render_widget(header, main_chunks[0]);
+
+    let status_time = app.status_time();
     let tab_line = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Min(0), Constraint::Length(12)])
-        .split(main_chunks[0]);
-
-    let tab_text = Line::from(vec![Span::styled(
-        format!("{} TAB", app.current_tab.name()),
-        Style::default().fg(Color::DarkGray),
-    )]);
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(status_time.chars().count() as u16 + 2),
+            Constraint::Length(tab_bar_width()),
+        ])
+        .split(main_chunks[1]);
 
-    let tabs = Paragraph::new(tab_text)
+    let time_widget = Paragraph::new(status_time)
         .alignment(Alignment::Right)
-        .wrap(Wrap { trim: false });
-    f.render_widget(tabs, tab_line[1]);
+        .style(Style::default().fg(app.theme.text));
+    f.render_widget(time_widget, tab_line[1]);
+
+    let tabs = Tabs::new(TABS.iter().map(|tab| tab.name()))
+        .select(app.current_tab.index())
+        .style(Style::default().fg(app.theme.muted))
+        .highlight_style(
+            Style::default()
+                .fg(app.theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(tabs, tab_line[2]);
+    app.tab_area = tab_line[2];
+
+    let mut status_spans = Vec::new();
+    if let Some(message) = &app.message {
+        status_spans.push(Span::styled(
+            format!("{message} "),
+            Style::default().fg(app.theme.levels[2]),
+        ));
+    }
+    if app.quit_pending() {
+        status_spans.push(Span::styled(
+            "Press q again to quit ",
+            Style::default()
+                .fg(app.theme.levels[3])
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.paused {
+        status_spans.push(Span::styled(
+            "PAUSED ",
+            Style::default()
+                .fg(app.theme.levels[2])
+                .add_modifier(Modifier::BOLD),
+        ));
+    } else if app.manual {
+        status_spans.push(Span::styled(
+            "MANUAL ",
+            Style::default()
+                .fg(app.theme.levels[2])
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    status_spans.push(Span::styled(
+        format!(
+            "up {} since {} | every {}s",
+            format_uptime(app.metrics.uptime),
+            app.boot_time,
+            app.refresh_interval.as_secs_f64()
+        ),
+        Style::default().fg(app.theme.muted),
+    ));
+    f.render_widget(Paragraph::new(Line::from(status_spans)), tab_line[0]);
+
+    if app.oom_warning {
+        let banner = Paragraph::new(" LOW MEMORY: system is close to running out of memory ")
+            .alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
+            );
+        f.render_widget(banner, main_chunks[2]);
+    }
 
     match app.current_tab {
-        Tab::Perf => render_perf_tab(f, app, main_chunks[1]),
-        Tab::Clock => render_clock_tab(f, app, main_chunks[1]),
+        Tab::Overview => render_perf_tab(f, app, main_chunks[3]),
+        Tab::Clock => render_clock_tab(f, app, main_chunks[3]),
+        Tab::Process => render_process_tab(f, app, main_chunks[3]),
+        Tab::Network => render_network_tab(f, app, main_chunks[3]),
+        Tab::Disk => render_disk_tab(f, app, main_chunks[3]),
+    }
+
+    render_cpu_history(f, app, main_chunks[4]);
+
+    if let Some(kill) = &app.pending_kill {
+        render_kill_confirm(f, app, kill);
+    }
+
+    if app.show_help {
+        render_help(f, app);
     }
+}
+
+fn render_kill_confirm(f: &mut Frame, app: &App, kill: &PendingKill) {
+    let text = vec![
+        Line::from(format!(
+            "Send {} to {} ({})?",
+            kill.signal_name(),
+            kill.pid,
+            kill.name
+        )),
+        Line::from(Span::styled(
+            "y to confirm, any other key to cancel",
+            Style::default().fg(app.theme.muted),
+        )),
+    ];
+
+    let width = (text[0].width() as u16 + 4).max(42).min(f.area().width);
+    let area = f
+        .area()
+        .centered(Constraint::Length(width), Constraint::Length(4));
+
+    let confirm = Paragraph::new(text)
+        .centered()
+        .block(app.theme.block(" Kill process "));
+    f.render_widget(Clear, area);
+    f.render_widget(confirm, area);
+}
+
+fn render_help(f: &mut Frame, app: &App) {
+    let lines: Vec<Line> = HELP_KEYS
+        .iter()
+        .map(|(key, action)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{key:>8}  "),
+                    Style::default().fg(app.theme.levels[1]),
+                ),
+                Span::styled(*action, Style::default().fg(app.theme.text)),
+            ])
+        })
+        .collect();
+
+    let width = 44.min(f.area().width);
+    let height = (lines.len() as u16 + 2).min(f.area().height);
+    let area = f
+        .area()
+        .centered(Constraint::Length(width), Constraint::Length(height));
+
+    let help = Paragraph::new(lines).block(app.theme.block(" Keys "));
+    f.render_widget(Clear, area);
+    f.render_widget(help, area);
+}
 
-    let sparkline_data: Vec<u64> = app
+fn render_cpu_history(f: &mut Frame, app: &App, area: Rect) {
+    let dots = area.width as usize * 2;
+    let points: Vec<(f64, f64)> = app
         .cpu_history
         .iter()
-        .map(|&x| if x < 10 { 10 } else { x })
+        .take(dots)
+        .enumerate()
+        .map(|(index, &usage)| ((dots - 1 - index) as f64, usage))
         .collect();
+    let color = app.theme.muted;
+    let extremes: Vec<(f64, f64, String, Color)> = if app.show_extremes {
+        [
+            (app.cpu_peak, "▲", app.theme.levels[3]),
+            (app.cpu_trough, "▼", app.theme.levels[0]),
+        ]
+        .into_iter()
+        .filter_map(|(index, symbol, color)| {
+            let index = index.filter(|&index| index < dots)?;
+            let usage = app.cpu_history[index];
+            let time = app.cpu_history_times[index].format("%H:%M:%S");
+            let label = format!("{symbol} {usage:.0}% {time}");
+            let x = (dots - 1 - index) as f64;
+            let label_dots = (label.chars().count() * 2) as f64;
+            let x = if x + label_dots > dots as f64 {
+                (x - label_dots).max(0.0)
+            } else {
+                x
+            };
+            Some((x, usage, label, color))
+        })
+        .collect()
+    } else {
+        Vec::new()
+    };
 
-    let sparkline = Sparkline::default()
-        .data(&sparkline_data)
-        .max(100)
-        .style(Style::default().fg(Color::DarkGray))
-        .direction(RenderDirection::RightToLeft);
-    f.render_widget(sparkline, main_chunks[2]);
+    let canvas = Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([0.0, dots.saturating_sub(1) as f64])
+        .y_bounds([0.0, 100.0])
+        .paint(move |ctx| {
+            for pair in points.windows(2) {
+                ctx.draw(&CanvasLine::new(
+                    pair[0].0, pair[0].1, pair[1].0, pair[1].1, color,
+                ));
+            }
+            for (x, y, label, color) in &extremes {
+                ctx.print(
+                    *x,
+                    *y,
+                    Span::styled(label.clone(), Style::default().fg(*color)),
+                );
+            }
+        });
+    f.render_widget(canvas, area);
 }
 
-fn render_perf_tab(f: &mut Frame, app: &mut App, area: ratatui::prelude::Rect) {
+fn render_perf_tab(f: &mut Frame, app: &mut App, area: Rect) {
     let horizontal_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(20),
-            Constraint::Percentage(60),
-            Constraint::Percentage(20),
-        ])
+        .constraints(match app.layout {
+            LayoutMode::Centered => [
+                Constraint::Percentage(app.padding),
+                Constraint::Percentage(100 - app.padding * 2),
+                Constraint::Percentage(app.padding),
+            ],
+            LayoutMode::Full => [
+                Constraint::Length(0),
+                Constraint::Min(0),
+                Constraint::Length(0),
+            ],
+        })
         .split(area);
 
+    if let Some(panel) = app.expanded_panel {
+        let expanded_area = horizontal_chunks[1].inner(Margin::new(0, 1));
+        render_panel(f, app, panel, expanded_area, true);
+        app.panel_areas = vec![(panel, expanded_area)];
+        return;
+    }
+
+    let mut panels: Vec<Panel> = PANELS
+        .into_iter()
+        .filter(|panel| panel.enabled(app))
+        .collect();
+    let bar_panels: Vec<Panel> = if app.vertical {
+        panels.iter().copied().filter(Panel::vertical).collect()
+    } else {
+        Vec::new()
+    };
+    panels.retain(|panel| !bar_panels.contains(panel));
+    let bars_height = if bar_panels.is_empty() {
+        0
+    } else {
+        VERTICAL_BARS_HEIGHT
+    };
+    let total_height: u16 = bars_height + panels.iter().map(|panel| panel.height(app)).sum::<u16>();
+
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(20),
-            Constraint::Length(15),
+            match app.layout {
+                LayoutMode::Centered => Constraint::Percentage(20),
+                LayoutMode::Full => Constraint::Length(0),
+            },
+            Constraint::Length(total_height + 2),
             Constraint::Min(0),
         ])
         .split(horizontal_chunks[1]);
@@ -360,62 +1914,861 @@ fn render_perf_tab(f: &mut Frame, app: &mut App, area: ratatui::prelude::Rect) {
     let widget_chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
-        .constraints([
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(3),
-            Constraint::Length(1),
-        ])
+        .constraints(
+            std::iter::once(Constraint::Length(bars_height)).chain(
+                panels
+                    .iter()
+                    .map(|panel| Constraint::Length(panel.height(app))),
+            ),
+        )
         .split(vertical_chunks[1]);
 
-    let cpu_title = format!(
-        " CPU ({:.2} {:.2} {:.2}) ",
-        app.load_avg_1, app.load_avg_5, app.load_avg_15
+    app.panel_areas.clear();
+    let bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, bar_panels.len().max(1) as u32);
+            bar_panels.len()
+        ])
+        .split(widget_chunks[0]);
+    for (&panel, &panel_area) in bar_panels.iter().zip(bar_chunks.iter()) {
+        render_vertical_bar(f, app, panel, panel_area);
+        app.panel_areas.push((panel, panel_area));
+    }
+    for (&panel, &panel_area) in panels.iter().zip(widget_chunks.iter().skip(1)) {
+        render_panel(f, app, panel, panel_area, false);
+        app.panel_areas.push((panel, panel_area));
+    }
+}
+
+fn render_vertical_bar(f: &mut Frame, app: &App, panel: Panel, area: Rect) {
+    let (title, percent, shown, alerting) = match panel {
+        Panel::Cpu => (" CPU ", app.metrics.cpu_usage, app.shown_cpu, app.cpu_alert),
+        Panel::Memory => (
+            " Memory ",
+            app.metrics.memory_percent,
+            app.shown_memory,
+            app.memory_alert,
+        ),
+        _ => (" Swap ", app.metrics.swap_percent, app.shown_swap, false),
+    };
+    let bar = Bar::default()
+        .value(shown.round() as u64)
+        .text_value(format!("{percent:.0}%"))
+        .style(Style::default().fg(get_gauge_color(percent, &app.thresholds, &app.theme)))
+        .value_style(
+            Style::default()
+                .fg(app.theme.text)
+                .add_modifier(Modifier::REVERSED),
+        );
+    let chart = BarChart::vertical([bar])
+        .block(alert_block(app, title, alerting))
+        .bar_width(area.width.saturating_sub(2).max(1))
+        .max(100);
+    f.render_widget(chart, area);
+}
+
+fn render_panel(f: &mut Frame, app: &App, panel: Panel, area: Rect, expanded: bool) {
+    match panel {
+        Panel::Watched => render_watched(f, app, area),
+        Panel::Cpu => render_cpu(f, app, area, expanded),
+        Panel::Memory => render_memory(f, app, area, expanded),
+        Panel::Swap => render_swap(f, app, area, expanded),
+        Panel::Network => render_network(f, app, area),
+        Panel::Battery => render_battery(f, app, area),
+        Panel::Gpu => render_gpu(f, app, area),
+        Panel::Disk => render_disk(f, app, area),
+        Panel::Numa => render_numa(f, app, area),
+        Panel::Kernel => render_kernel(f, app, area),
+        Panel::Sensors => render_sensors(f, app, area),
+    }
+}
+
+fn render_details(f: &mut Frame, app: &App, area: Rect, details: Vec<String>) {
+    let lines: Vec<Line> = details
+        .into_iter()
+        .map(|detail| Line::styled(detail, Style::default().fg(app.theme.text)))
+        .collect();
+    f.render_widget(Paragraph::new(lines).centered(), area);
+}
+
+fn cpu_title(app: &App, precision: usize) -> Line<'static> {
+    let cores = app.metrics.cpu_usages.len();
+    let window_load = app.load_window.map(|window| match window {
+        LoadWindow::One => app.metrics.load_avg_1,
+        LoadWindow::Five => app.metrics.load_avg_5,
+        LoadWindow::Fifteen => app.metrics.load_avg_15,
+    });
+    let load_span = |load: f64| {
+        Span::styled(
+            format!("{load:.precision$}"),
+            Style::default().fg(load_color(window_load.unwrap_or(load), cores, &app.theme)),
+        )
+    };
+    let mut title_spans = vec![
+        Span::raw(" CPU ("),
+        load_span(app.metrics.load_avg_1),
+        Span::raw(" "),
+        load_span(app.metrics.load_avg_5),
+        Span::raw(" "),
+        load_span(app.metrics.load_avg_15),
+        Span::raw(") "),
+    ];
+    if let Some(temperature) = app.metrics.cpu_temperature {
+        title_spans.push(Span::raw(format!(
+            "{} ",
+            format_temperature(temperature, app.fahrenheit)
+        )));
+    }
+    if app.metrics.cpu_frequency > 0 {
+        title_spans.push(Span::raw(format!(
+            "{:.1} GHz ",
+            app.metrics.cpu_frequency as f64 / 1000.0
+        )));
+    }
+    title_spans.push(Span::raw(format!("max {:.0}% ", app.cpu_stats.max)));
+    Line::from(title_spans)
+}
+
+fn render_cpu(f: &mut Frame, app: &App, area: Rect, expanded: bool) {
+    let cpu_title = [2, 1, 0]
+        .into_iter()
+        .map(|precision| cpu_title(app, precision))
+        .find(|title| title.width() + 2 <= area.width as usize)
+        .unwrap_or_else(|| cpu_title(app, 0));
+    let mut cpu_block = alert_block(app, cpu_title, app.cpu_alert);
+    if let Some(breakdown) = &app.metrics.cpu_breakdown {
+        cpu_block = cpu_block.title_bottom(
+            Line::from(format!(
+                " us {:.0}% sy {:.0}% wa {:.0}% st {:.0}% ",
+                breakdown.user, breakdown.system, breakdown.iowait, breakdown.steal
+            ))
+            .right_aligned(),
+        );
+    }
+
+    let per_core = app.per_core || expanded;
+    let gauge_height = if per_core {
+        let (rows, _) = core_grid_shape(app.metrics.cpu_usages.len());
+        rows as u16 + 2
+    } else {
+        3
+    };
+    let cpu_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(gauge_height), Constraint::Min(0)])
+        .split(area);
+
+    if per_core {
+        render_per_core(f, app, cpu_chunks[0], cpu_block);
+    } else {
+        let cpu_gauge = Gauge::default()
+            .block(cpu_block)
+            .gauge_style(Style::default().fg(get_gauge_color(
+                app.metrics.cpu_usage,
+                &app.thresholds,
+                &app.theme,
+            )))
+            .percent(app.shown_cpu as u16)
+            .label(severity_label(
+                app,
+                app.metrics.cpu_usage,
+                format!(
+                    "{} {}",
+                    gauge_label(
+                        app,
+                        app.metrics.cpu_usage,
+                        format!(
+                            "{:.1} / {} cores",
+                            app.metrics.cpu_usage / 100.0 * app.metrics.cpu_usages.len() as f64,
+                            app.metrics.cpu_usages.len()
+                        ),
+                        LabelMode::Percent,
+                    ),
+                    app.cpu_trend.symbol()
+                ),
+            ));
+        f.render_widget(cpu_gauge, cpu_chunks[0]);
+    }
+
+    render_cpu_history(f, app, cpu_chunks[1]);
+}
+
+fn render_memory(f: &mut Frame, app: &App, area: Rect, expanded: bool) {
+    let memory_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let memory_known = app.metrics.total_memory > 0;
+    let memory_title = if memory_known {
+        format!(
+            " Memory ({:.1}%, max {:.0}%) ",
+            app.metrics.memory_percent, app.memory_stats.max
+        )
+    } else {
+        " Memory ".to_string()
+    };
+    let memory_label = match &app.mem_label {
+        Some(template) => expand_label(
+            template,
+            &[
+                ("used", format_gigabytes(app.metrics.used_memory, app.si)),
+                ("total", format_gigabytes(app.metrics.total_memory, app.si)),
+                ("cache", format_gigabytes(app.metrics.cached_memory, app.si)),
+                (
+                    "available",
+                    format_gigabytes(app.metrics.available_memory, app.si),
+                ),
+                ("pct", format!("{:.0}", app.metrics.memory_percent)),
+            ],
+        ),
+        None => format!(
+            "{} + {} cache",
+            format_gigabytes(app.metrics.used_memory, app.si),
+            format_gigabytes(app.metrics.cached_memory, app.si)
+        ),
+    };
+    let memory_block = alert_block(app, memory_title, app.memory_alert);
+    let gauge_area = memory_block.inner(memory_chunks[0]);
+    f.render_widget(memory_block, memory_chunks[0]);
+    render_segmented_gauge(
+        f,
+        app,
+        gauge_area,
+        &[
+            (
+                app.shown_memory_segments[0],
+                get_gauge_color(app.metrics.memory_percent, &app.thresholds, &app.theme),
+            ),
+            (app.shown_memory_segments[1], app.theme.muted),
+        ],
+        &if memory_known {
+            severity_label(
+                app,
+                app.metrics.memory_percent,
+                format!(
+                    "{} {}",
+                    gauge_label(
+                        app,
+                        app.metrics.memory_percent,
+                        memory_label,
+                        LabelMode::Absolute,
+                    ),
+                    app.memory_trend.symbol()
+                ),
+            )
+        } else {
+            "unknown".to_string()
+        },
+    );
+
+    if expanded {
+        let details = vec![
+            format!(
+                "Total      {}",
+                format_gigabytes(app.metrics.total_memory, app.si)
+            ),
+            format!(
+                "Used       {}",
+                format_gigabytes(app.metrics.used_memory, app.si)
+            ),
+            format!(
+                "Cache      {}",
+                format_gigabytes(app.metrics.cached_memory, app.si)
+            ),
+            format!(
+                "Available  {}",
+                format_gigabytes(app.metrics.available_memory, app.si)
+            ),
+            format!("Session    {}", app.memory_stats.summary()),
+        ];
+        render_details(f, app, memory_chunks[1], details);
+    }
+}
+
+fn render_segmented_gauge(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    segments: &[(f64, Color)],
+    label: &str,
+) {
+    let width = area.width as f64;
+    let mut start = 0.0;
+    let mut spans = Vec::new();
+    for &(percent, color) in segments {
+        let end = (start + percent.clamp(0.0, 100.0) / 100.0 * width).min(width);
+        let cells = end.round() as usize - start.round() as usize;
+        spans.push(Span::styled(" ".repeat(cells), Style::default().bg(color)));
+        start = end;
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+
+    let label_width = (label.chars().count() as u16).min(area.width);
+    let x = area.x + (area.width - label_width) / 2;
+    let y = area.y + area.height / 2;
+    f.buffer_mut().set_stringn(
+        x,
+        y,
+        label,
+        label_width as usize,
+        Style::default()
+            .fg(app.theme.text)
+            .add_modifier(Modifier::BOLD),
     );
+}
+
+fn render_swap(f: &mut Frame, app: &App, area: Rect, expanded: bool) {
+    let swap_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let (swap_title, swap_label) = if app.metrics.total_swap == 0 {
+        (" Swap ".to_string(), "disabled".to_string())
+    } else {
+        (
+            format!(
+                " Swap ({:.1}%, max {:.0}%) ",
+                app.metrics.swap_percent, app.swap_stats.max
+            ),
+            severity_label(
+                app,
+                app.metrics.swap_percent,
+                format!(
+                    "{} {}",
+                    gauge_label(
+                        app,
+                        app.metrics.swap_percent,
+                        format_gigabytes(app.metrics.used_swap, app.si),
+                        LabelMode::Absolute,
+                    ),
+                    app.swap_trend.symbol()
+                ),
+            ),
+        )
+    };
+    let mut swap_block = app.theme.block(swap_title);
+    if let Some(rates) = app
+        .metrics
+        .swap_rates
+        .as_ref()
+        .filter(|_| app.metrics.total_swap > 0)
+    {
+        swap_block = swap_block.title_bottom(
+            Line::from(format!(
+                " pages in {} out {} ",
+                format_event_rate(rates.pages_in),
+                format_event_rate(rates.pages_out)
+            ))
+            .right_aligned(),
+        );
+    }
+    let swap_gauge = Gauge::default()
+        .block(swap_block)
+        .gauge_style(Style::default().fg(get_gauge_color(
+            app.metrics.swap_percent,
+            &app.thresholds,
+            &app.theme,
+        )))
+        .percent(app.shown_swap as u16)
+        .label(swap_label);
+    f.render_widget(swap_gauge, swap_chunks[0]);
+
+    if expanded {
+        let details = vec![
+            format!(
+                "Total  {}",
+                format_gigabytes(app.metrics.total_swap, app.si)
+            ),
+            format!("Used   {}", format_gigabytes(app.metrics.used_swap, app.si)),
+            format!("Session {}", app.swap_stats.summary()),
+        ];
+        render_details(f, app, swap_chunks[1], details);
+    }
+}
+
+fn render_watched(f: &mut Frame, app: &App, area: Rect) {
+    let Some(process) = &app.watched else {
+        let pid = app.watch_pid.map(|pid| pid.to_string()).unwrap_or_default();
+        let exited = Paragraph::new("exited")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(app.theme.muted))
+            .block(app.theme.block(format!(" PID {pid} ")));
+        f.render_widget(exited, area);
+        return;
+    };
+
+    let block = app
+        .theme
+        .block(format!(" PID {} {} ", process.pid, process.name));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(inner);
+
+    let cores = app.metrics.cpu_usages.len().max(1) as f64;
+    let cpu_percent = process.cpu_usage / cores;
     let cpu_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(cpu_title))
-        .gauge_style(Style::default().fg(get_gauge_color(app.cpu_usage)))
-        .percent(app.cpu_usage as u16)
-        .label(format!("{:.1}%", app.cpu_usage));
-    f.render_widget(cpu_gauge, widget_chunks[0]);
+        .gauge_style(Style::default().fg(get_gauge_color(cpu_percent, &app.thresholds, &app.theme)))
+        .percent(cpu_percent.min(100.0) as u16)
+        .label(format!("cpu {:.1}%", process.cpu_usage));
+    f.render_widget(cpu_gauge, rows[0]);
 
-    let memory_title = format!(" Memory ({:.1}%) ", app.memory_percent);
+    let memory_percent = if app.metrics.total_memory == 0 {
+        0.0
+    } else {
+        process.memory as f64 / app.metrics.total_memory as f64 * 100.0
+    };
     let memory_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(memory_title))
-        .gauge_style(Style::default().fg(get_gauge_color(app.memory_percent)))
-        .percent(app.memory_percent as u16)
-        .label(format!("{:.1} GB", app.used_memory_gb));
-    f.render_widget(memory_gauge, widget_chunks[1]);
+        .gauge_style(Style::default().fg(get_gauge_color(
+            memory_percent,
+            &app.thresholds,
+            &app.theme,
+        )))
+        .percent(memory_percent.min(100.0) as u16)
+        .label(format!(
+            "mem {} ({memory_percent:.1}%)",
+            format_megabytes(process.memory, app.si)
+        ));
+    f.render_widget(memory_gauge, rows[1]);
+}
 
-    let swap_title = format!(" Swap ({:.1}%) ", app.swap_percent);
-    let swap_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(swap_title))
-        .gauge_style(Style::default().fg(get_gauge_color(app.swap_percent)))
-        .percent(app.swap_percent as u16)
-        .label(format!("{:.1} GB", app.used_swap_gb));
+fn render_battery(f: &mut Frame, app: &App, area: Rect) {
+    let Some(battery) = &app.metrics.battery else {
+        return;
+    };
 
-    f.render_widget(swap_gauge, widget_chunks[2]);
+    let mut battery_label = format!("{:.0}% {}", battery.percent, battery.status);
+    if let Some(remaining) = battery.time_remaining {
+        let minutes = remaining.as_secs() / 60;
+        battery_label.push_str(&format!(" {}:{:02} left", minutes / 60, minutes % 60));
+    }
+    let battery_gauge = Gauge::default()
+        .block(app.theme.block(" Battery "))
+        .gauge_style(Style::default().fg(get_gauge_color(
+            100.0 - battery.percent,
+            &app.thresholds,
+            &app.theme,
+        )))
+        .percent(battery.percent.clamp(0.0, 100.0) as u16)
+        .label(battery_label);
+    let gauge_area = Rect {
+        height: area.height.min(3),
+        ..area
+    };
+    f.render_widget(battery_gauge, gauge_area);
+}
+
+fn render_gpu(f: &mut Frame, app: &App, area: Rect) {
+    let Some(gpu) = &app.metrics.gpu else {
+        return;
+    };
+
+    let block = app.theme.block(match gpu.temperature {
+        Some(temperature) => format!(
+            " {} ({}) ",
+            gpu.name,
+            format_temperature(temperature as f64, app.fahrenheit)
+        ),
+        None => format!(" {} ", gpu.name),
+    });
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let gpu_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Length(2)])
+        .split(inner);
+
+    let utilization = gpu.utilization as f64;
+    let utilization_gauge = Gauge::default()
+        .gauge_style(Style::default().fg(get_gauge_color(utilization, &app.thresholds, &app.theme)))
+        .percent(gpu.utilization.min(100) as u16)
+        .label(severity_label(
+            app,
+            utilization,
+            format!("util {}%", gpu.utilization),
+        ));
+    f.render_widget(utilization_gauge, gpu_chunks[0]);
+
+    let vram_percent = if gpu.memory_total == 0 {
+        0.0
+    } else {
+        gpu.memory_used as f64 / gpu.memory_total as f64 * 100.0
+    };
+    let vram_gauge = Gauge::default()
+        .gauge_style(Style::default().fg(get_gauge_color(
+            vram_percent,
+            &app.thresholds,
+            &app.theme,
+        )))
+        .percent(vram_percent as u16)
+        .label(severity_label(
+            app,
+            vram_percent,
+            format!(
+                "vram {}",
+                gauge_label(
+                    app,
+                    vram_percent,
+                    format!(
+                        "{} / {}",
+                        format_gigabytes(gpu.memory_used, app.si),
+                        format_gigabytes(gpu.memory_total, app.si)
+                    ),
+                    LabelMode::Absolute,
+                )
+            ),
+        ));
+    f.render_widget(vram_gauge, gpu_chunks[1]);
+}
+
+fn render_disk(f: &mut Frame, app: &App, area: Rect) {
+    let disk_text = Line::from(vec![
+        Span::styled("R ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            format_rate(app.metrics.disk_read_rate),
+            Style::default().fg(app.theme.download),
+        ),
+        Span::styled("  W ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            format_rate(app.metrics.disk_write_rate),
+            Style::default().fg(app.theme.upload),
+        ),
+    ]);
+    let disk_widget = Paragraph::new(disk_text)
+        .alignment(Alignment::Center)
+        .block(app.theme.block(" Disk I/O "));
+    f.render_widget(disk_widget, area);
+}
+
+fn render_kernel(f: &mut Frame, app: &App, area: Rect) {
+    let Some(rates) = &app.metrics.kernel_rates else {
+        return;
+    };
+
+    let kernel_text = Line::from(vec![
+        Span::styled("ctxt ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            format_event_rate(rates.context_switches),
+            Style::default().fg(app.theme.text),
+        ),
+        Span::styled("  intr ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            format_event_rate(rates.interrupts),
+            Style::default().fg(app.theme.text),
+        ),
+    ]);
+    let kernel_widget = Paragraph::new(kernel_text)
+        .alignment(Alignment::Center)
+        .block(app.theme.block(" Kernel "));
+    f.render_widget(kernel_widget, area);
+}
+
+fn render_numa(f: &mut Frame, app: &App, area: Rect) {
+    let block = app.theme.block(" NUMA Memory ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
 
-    let network_gauge = Paragraph::new(format!(
-        "↓ {:.1} ↑ {:.1} KB/s",
-        app.network_download_kbps, app.network_upload_kbps
+    let row_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); app.metrics.numa_nodes.len()])
+        .split(inner);
+    for (node, &row_area) in app.metrics.numa_nodes.iter().zip(row_chunks.iter()) {
+        let percent = node.percent();
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(get_gauge_color(percent, &app.thresholds, &app.theme)))
+            .percent(percent as u16)
+            .label(format!(
+                "node{} {}",
+                node.id,
+                gauge_label(
+                    app,
+                    percent,
+                    format!(
+                        "{} / {}",
+                        format_gigabytes(node.used, app.si),
+                        format_gigabytes(node.total, app.si)
+                    ),
+                    LabelMode::Absolute,
+                )
+            ));
+        f.render_widget(gauge, row_area);
+    }
+}
+
+fn render_sensors(f: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(["Sensor", "Current", "Max"]).style(
+        Style::default()
+            .fg(app.theme.text)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows = app.metrics.sensors.iter().filter_map(|sensor| {
+        let temperature = sensor.temperature?;
+        let critical = sensor
+            .critical
+            .filter(|critical| *critical > 0.0)
+            .unwrap_or(DEFAULT_CRITICAL_TEMPERATURE);
+        let color = get_gauge_color(temperature / critical * 100.0, &app.thresholds, &app.theme);
+        let max = sensor
+            .max
+            .map(|max| format_temperature(max, app.fahrenheit))
+            .unwrap_or_default();
+        Some(
+            Row::new(vec![
+                sensor.label.clone(),
+                format_temperature(temperature, app.fahrenheit),
+                max,
+            ])
+            .style(Style::default().fg(color)),
+        )
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(16),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .block(app.theme.block(" Sensors "));
+    f.render_widget(table, area);
+}
+
+fn render_network(f: &mut Frame, app: &App, area: Rect) {
+    let block = app.theme.block(" Network ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let network_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+
+    let network_text = Paragraph::new(format!(
+        "↓ {} ↑ {}",
+        app.network_reading(
+            app.metrics.network_download_rate,
+            app.metrics.network_counter_reset
+        ),
+        app.network_reading(
+            app.metrics.network_upload_rate,
+            app.metrics.network_counter_reset
+        )
     ))
-    .block(Block::default().borders(Borders::ALL).title(" Network "))
     .centered();
+    f.render_widget(network_text, network_chunks[0]);
 
-    f.render_widget(network_gauge, widget_chunks[3]);
+    if app.show_interfaces {
+        render_interface_list(f, app, network_chunks[1]);
+    } else {
+        render_network_chart(f, app, network_chunks[1]);
+    }
+}
 
-    let info_text = format!(
-        "OS: {} | Kernel: {} | Uptime: {} days",
-        app.os_name, app.kernel_version, app.uptime_days
-    );
-    let info_widget = Paragraph::new(info_text)
-        .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Gray));
-    f.render_widget(info_widget, widget_chunks[4]);
+fn render_interface_list(f: &mut Frame, app: &App, area: Rect) {
+    let interface_lines: Vec<Line> = app
+        .metrics
+        .interface_rates
+        .iter()
+        .map(|rate| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", rate.name),
+                    Style::default().fg(app.theme.muted),
+                ),
+                Span::styled(
+                    format!("↓ {:>12}", app.network_reading(rate.download, rate.reset)),
+                    Style::default().fg(app.theme.download),
+                ),
+                Span::styled(
+                    format!(" ↑ {:>12}", app.network_reading(rate.upload, rate.reset)),
+                    Style::default().fg(app.theme.upload),
+                ),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(interface_lines).centered(), area);
+}
+
+fn render_network_chart(f: &mut Frame, app: &App, area: Rect) {
+    let to_points = |history: &VecDeque<f64>| -> Vec<(f64, f64)> {
+        let offset = app.metrics.history_len - history.len();
+        history
+            .iter()
+            .enumerate()
+            .map(|(i, &rate)| ((offset + i) as f64, rate))
+            .collect()
+    };
+    let download_points = to_points(&app.metrics.network_download_history);
+    let upload_points = to_points(&app.metrics.network_upload_history);
+    let max_rate = app
+        .metrics
+        .network_download_history
+        .iter()
+        .chain(app.metrics.network_upload_history.iter())
+        .fold(1.0, |max: f64, &rate| max.max(rate));
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(app.theme.download))
+            .data(&download_points),
+        Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(app.theme.upload))
+            .data(&upload_points),
+    ];
+
+    let chart = Chart::new(datasets)
+        .x_axis(Axis::default().bounds([0.0, (app.metrics.history_len - 1) as f64]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, max_rate])
+                .labels([Span::raw(""), Span::raw(app.network_rate(max_rate))])
+                .style(Style::default().fg(app.theme.muted)),
+        );
+    f.render_widget(chart, area);
+}
+
+fn render_per_core(f: &mut Frame, app: &App, area: Rect, block: Block) {
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let (rows, columns) = core_grid_shape(app.metrics.cpu_usages.len());
+    let column_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .spacing(1)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(inner);
+
+    for (column, column_area) in column_chunks.iter().enumerate() {
+        let row_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); rows])
+            .split(*column_area);
+
+        for (row, row_area) in row_chunks.iter().enumerate() {
+            let index = column * rows + row;
+            if let Some(&usage) = app.metrics.cpu_usages.get(index) {
+                let busiest = app.busiest_core == Some(index);
+                let marker = if busiest { "▶" } else { " " };
+                let mut style =
+                    Style::default().fg(get_gauge_color(usage, &app.thresholds, &app.theme));
+                if busiest {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                let core_gauge = Gauge::default()
+                    .gauge_style(style)
+                    .percent(usage as u16)
+                    .label(format!("{marker}{index} {usage:.0}%"));
+                f.render_widget(core_gauge, *row_area);
+            }
+        }
+    }
+}
+
+fn render_clock_tab(f: &mut Frame, app: &mut App, area: Rect) {
+    if app.show_stopwatch {
+        clock::render_stopwatch(
+            f,
+            area,
+            &app.stopwatch,
+            &app.clock_color(),
+            app.clock_font,
+            app.clock_scale,
+        );
+        return;
+    }
+    let style = ClockStyle {
+        color: app.clock_color(),
+        twelve_hour: app.twelve_hour,
+        leading_zero: app.clock_leading_zero,
+        date_format: &app.clock_date_format,
+        font: app.clock_font,
+        scale: app.clock_scale,
+    };
+    clock::render_clock(f, area, &style);
+}
+
+fn render_network_tab(f: &mut Frame, app: &mut App, area: Rect) {
+    let network_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Min(0)])
+        .split(area);
+
+    let block = app.theme.block(format!(
+        " Network ↓ {} ↑ {} ",
+        app.network_reading(
+            app.metrics.network_download_rate,
+            app.metrics.network_counter_reset
+        ),
+        app.network_reading(
+            app.metrics.network_upload_rate,
+            app.metrics.network_counter_reset
+        )
+    ));
+    let inner = block.inner(network_chunks[0]);
+    f.render_widget(block, network_chunks[0]);
+    render_network_chart(f, app, inner);
+
+    let block = app.theme.block(" Interfaces ");
+    let inner = block.inner(network_chunks[1]);
+    f.render_widget(block, network_chunks[1]);
+    render_interface_list(f, app, inner);
+}
+
+fn render_disk_tab(f: &mut Frame, app: &mut App, area: Rect) {
+    let disk_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+    render_disk(f, app, disk_chunks[0]);
+
+    let block = app.theme.block(" Disks ");
+    let inner = block.inner(disk_chunks[1]);
+    f.render_widget(block, disk_chunks[1]);
+
+    let row_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); app.metrics.disks.len()])
+        .split(inner);
+    for (disk, &row_area) in app.metrics.disks.iter().zip(row_chunks.iter()) {
+        let total = disk.total;
+        let used = total.saturating_sub(disk.available);
+        let percent = if total == 0 {
+            0.0
+        } else {
+            used as f64 / total as f64 * 100.0
+        };
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(24), Constraint::Min(0)])
+            .split(row_area);
+        f.render_widget(
+            Paragraph::new(disk.mount_point.as_str()).style(Style::default().fg(app.theme.muted)),
+            row[0],
+        );
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(get_gauge_color(percent, &app.thresholds, &app.theme)))
+            .percent(percent as u16)
+            .label(format!(
+                "{} / {}",
+                format_gigabytes(used, app.si),
+                format_gigabytes(total, app.si)
+            ));
+        f.render_widget(gauge, row[1]);
+    }
 }
 
-fn render_clock_tab(f: &mut Frame, app: &mut App, area: ratatui::prelude::Rect) {
-    clock::render_clock(f, area, app.clock_color());
+fn render_process_tab(f: &mut Frame, app: &mut App, area: Rect) {
+    app.process_view.render(f, area, &app.theme, app.si);
 }
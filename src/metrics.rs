@@ -0,0 +1,613 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use sysinfo::{
+    Components, Disks, Networks, Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System,
+};
+
+use crate::{
+    battery::{self, Battery},
+    cpustat::{self, CpuBreakdown, CpuTimes, KernelCounters, KernelRates},
+    gpu::{self, Gpu, Nvml},
+    numa::{self, NumaNode},
+    vmstat::{self, SwapCounters, SwapRates},
+};
+
+pub const NETWORK_HISTORY_LEN: usize = 60;
+
+#[derive(Clone, Copy)]
+pub struct Scope {
+    pub processes: bool,
+    pub network: bool,
+    pub sensors: bool,
+    pub disks: bool,
+}
+
+impl Scope {
+    pub const ALL: Scope = Scope {
+        processes: true,
+        network: true,
+        sensors: true,
+        disks: true,
+    };
+}
+
+#[derive(Clone)]
+pub struct InterfaceRate {
+    pub name: String,
+    pub download: f64,
+    pub upload: f64,
+    pub reset: bool,
+}
+
+#[derive(Default)]
+pub struct InterfaceFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl InterfaceFilter {
+    pub fn allows(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| glob_match(p, name)))
+            && !self.exclude.iter().any(|p| glob_match(p, name))
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain(std::iter::once(name.len()))
+                .any(|index| glob_match(rest, &name[index..]))
+        }
+        Some(first) => name.chars().next().is_some_and(|ch| {
+            (first == '?' || first == ch)
+                && glob_match(&pattern[first.len_utf8()..], &name[ch.len_utf8()..])
+        }),
+    }
+}
+
+#[derive(Clone)]
+pub struct ProcessInfo {
+    pub pid: Pid,
+    pub name: String,
+    pub cpu_usage: f64,
+    pub memory: u64,
+    pub start_time: u64,
+    pub thread: bool,
+}
+
+impl ProcessInfo {
+    fn from_process(process: &Process) -> ProcessInfo {
+        ProcessInfo {
+            pid: process.pid(),
+            name: process.name().to_string_lossy().into_owned(),
+            cpu_usage: process.cpu_usage() as f64,
+            memory: process.memory(),
+            start_time: process.start_time(),
+            thread: process.thread_kind().is_some(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Sensor {
+    pub label: String,
+    pub temperature: Option<f64>,
+    pub max: Option<f64>,
+    pub critical: Option<f64>,
+}
+
+#[derive(Clone)]
+pub struct DiskSpace {
+    pub mount_point: String,
+    pub total: u64,
+    pub available: u64,
+}
+
+#[derive(Clone)]
+pub struct Metrics {
+    pub cpu_usage: f64,
+    pub cpu_usages: Vec<f64>,
+    pub cpu_temperature: Option<f64>,
+    pub cpu_frequency: u64,
+    pub cpu_breakdown: Option<CpuBreakdown>,
+    pub memory_percent: f64,
+    pub swap_percent: f64,
+    pub used_memory: u64,
+    pub cached_memory: u64,
+    pub available_memory: u64,
+    pub total_memory: u64,
+    pub used_swap: u64,
+    pub total_swap: u64,
+    pub swap_rates: Option<SwapRates>,
+    pub network_upload_rate: f64,
+    pub network_download_rate: f64,
+    pub interface_rates: Vec<InterfaceRate>,
+    pub network_counter_reset: bool,
+    pub network_download_history: VecDeque<f64>,
+    pub network_upload_history: VecDeque<f64>,
+    pub history_len: usize,
+    pub disk_read_rate: f64,
+    pub disk_write_rate: f64,
+    pub kernel_rates: Option<KernelRates>,
+    pub battery: Option<Battery>,
+    pub gpu: Option<Gpu>,
+    pub numa_nodes: Vec<NumaNode>,
+    pub load_avg_1: f64,
+    pub load_avg_5: f64,
+    pub load_avg_15: f64,
+    pub process_count: usize,
+    pub thread_count: usize,
+    pub uptime: u64,
+    pub processes: Vec<ProcessInfo>,
+    pub sensors: Vec<Sensor>,
+    pub disks: Vec<DiskSpace>,
+}
+
+pub struct Sampler {
+    pub metrics: Metrics,
+    system: System,
+    networks: Networks,
+    components: Components,
+    disks: Disks,
+    nvml: Option<Nvml>,
+    memory_available: bool,
+    all_interfaces: bool,
+    interface_filter: InterfaceFilter,
+    prev_scope: Scope,
+    prev_cpu_times: Option<CpuTimes>,
+    prev_kernel_counters: Option<KernelCounters>,
+    prev_swap_counters: Option<SwapCounters>,
+    prev_network_counters: HashMap<String, (u64, u64)>,
+    prev_counters_at: Instant,
+}
+
+fn cpu_temperature(components: &Components) -> Option<f64> {
+    components
+        .list()
+        .iter()
+        .filter(|c| c.label().contains("Package") || c.label().contains("CPU"))
+        .find_map(|c| c.temperature())
+        .filter(|t| t.is_finite() && *t > 0.0)
+        .map(|t| t as f64)
+}
+
+fn sensors(components: &Components) -> Vec<Sensor> {
+    components
+        .list()
+        .iter()
+        .map(|component| Sensor {
+            label: component.label().to_string(),
+            temperature: component.temperature().map(f64::from),
+            max: component.max().map(f64::from),
+            critical: component.critical().map(f64::from),
+        })
+        .collect()
+}
+
+fn disk_space(disks: &Disks) -> Vec<DiskSpace> {
+    disks
+        .list()
+        .iter()
+        .map(|disk| DiskSpace {
+            mount_point: disk.mount_point().to_string_lossy().into_owned(),
+            total: disk.total_space(),
+            available: disk.available_space(),
+        })
+        .collect()
+}
+
+fn processes(system: &System) -> Vec<ProcessInfo> {
+    system
+        .processes()
+        .values()
+        .map(ProcessInfo::from_process)
+        .collect()
+}
+
+fn cpu_frequency(system: &System) -> u64 {
+    let cpus = system.cpus();
+    if cpus.is_empty() {
+        return 0;
+    }
+    cpus.iter().map(|cpu| cpu.frequency()).sum::<u64>() / cpus.len() as u64
+}
+
+fn cached_memory(system: &System) -> u64 {
+    system
+        .available_memory()
+        .saturating_sub(system.free_memory())
+}
+
+fn memory_percent(system: &System, memory_available: bool) -> f64 {
+    let used = if memory_available {
+        system
+            .total_memory()
+            .saturating_sub(system.available_memory())
+    } else {
+        system.used_memory()
+    };
+    match system.total_memory() {
+        0 => 0.0,
+        total => (used as f64 / total as f64) * 100.0,
+    }
+}
+
+fn swap_percent(system: &System) -> f64 {
+    match system.total_swap() {
+        0 => 0.0,
+        total => (system.used_swap() as f64 / total as f64) * 100.0,
+    }
+}
+
+fn is_loopback(name: &str) -> bool {
+    name == "lo" || name == "lo0"
+}
+
+fn network_counters(
+    networks: &Networks,
+    all_interfaces: bool,
+    filter: &InterfaceFilter,
+) -> HashMap<String, (u64, u64)> {
+    networks
+        .iter()
+        .filter(|(name, _)| (all_interfaces || !is_loopback(name)) && filter.allows(name))
+        .map(|(name, data)| {
+            (
+                name.clone(),
+                (data.total_received(), data.total_transmitted()),
+            )
+        })
+        .collect()
+}
+
+fn disk_io_bytes(system: &System) -> (u64, u64) {
+    system
+        .processes()
+        .values()
+        .map(|process| process.disk_usage())
+        .fold((0, 0), |(read, written), usage| {
+            (read + usage.read_bytes, written + usage.written_bytes)
+        })
+}
+
+fn process_counts(system: &System) -> (usize, usize) {
+    system
+        .processes()
+        .values()
+        .filter(|process| process.thread_kind().is_none())
+        .fold((0, 0), |(processes, threads), process| {
+            let tasks = process.tasks().map_or(0, |tasks| {
+                tasks.iter().filter(|&&pid| pid != process.pid()).count()
+            });
+            (processes + 1, threads + tasks + 1)
+        })
+}
+
+impl Sampler {
+    pub fn new(memory_available: bool, all_interfaces: bool) -> Sampler {
+        let mut system = System::new_all();
+        system.refresh_all();
+        let networks = Networks::new_with_refreshed_list();
+        let components = Components::new_with_refreshed_list();
+        let disks = Disks::new_with_refreshed_list();
+        let nvml = gpu::init();
+        let load_avg = System::load_average();
+        let (process_count, thread_count) = process_counts(&system);
+
+        let metrics = Metrics {
+            cpu_usage: system.global_cpu_usage() as f64,
+            cpu_usages: system.cpus().iter().map(|c| c.cpu_usage() as f64).collect(),
+            cpu_temperature: cpu_temperature(&components),
+            cpu_frequency: cpu_frequency(&system),
+            cpu_breakdown: None,
+            memory_percent: memory_percent(&system, memory_available),
+            swap_percent: swap_percent(&system),
+            used_memory: system.used_memory(),
+            cached_memory: cached_memory(&system),
+            available_memory: system.available_memory(),
+            total_memory: system.total_memory(),
+            used_swap: system.used_swap(),
+            total_swap: system.total_swap(),
+            swap_rates: None,
+            network_upload_rate: 0.0,
+            network_download_rate: 0.0,
+            interface_rates: Vec::new(),
+            network_counter_reset: false,
+            network_download_history: VecDeque::with_capacity(NETWORK_HISTORY_LEN),
+            network_upload_history: VecDeque::with_capacity(NETWORK_HISTORY_LEN),
+            history_len: NETWORK_HISTORY_LEN,
+            disk_read_rate: 0.0,
+            disk_write_rate: 0.0,
+            kernel_rates: None,
+            battery: battery::read_battery(),
+            gpu: nvml.as_ref().and_then(gpu::read_gpu),
+            numa_nodes: numa::read_numa_nodes(),
+            load_avg_1: load_avg.one,
+            load_avg_5: load_avg.five,
+            load_avg_15: load_avg.fifteen,
+            process_count,
+            thread_count,
+            uptime: System::uptime(),
+            processes: processes(&system),
+            sensors: sensors(&components),
+            disks: disk_space(&disks),
+        };
+
+        Sampler {
+            metrics,
+            prev_cpu_times: cpustat::read_cpu_times(),
+            prev_kernel_counters: cpustat::read_kernel_counters(),
+            prev_swap_counters: vmstat::read_swap_counters(),
+            prev_network_counters: network_counters(
+                &networks,
+                all_interfaces,
+                &InterfaceFilter::default(),
+            ),
+            prev_counters_at: Instant::now(),
+            system,
+            networks,
+            components,
+            disks,
+            nvml,
+            memory_available,
+            all_interfaces,
+            interface_filter: InterfaceFilter::default(),
+            prev_scope: Scope::ALL,
+        }
+    }
+
+    pub fn with_interface_filter(mut self, filter: InterfaceFilter) -> Sampler {
+        self.prev_network_counters = network_counters(&self.networks, self.all_interfaces, &filter);
+        self.interface_filter = filter;
+        self
+    }
+
+    pub fn refresh(&mut self, min_interval: Duration, scope: Scope) {
+        self.system.refresh_cpu_all();
+        self.system.refresh_memory();
+        let process_kind = if scope.processes {
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_disk_usage()
+        } else {
+            ProcessRefreshKind::nothing()
+        };
+        self.system
+            .refresh_processes_specifics(ProcessesToUpdate::All, true, process_kind);
+        if scope.network {
+            self.networks.refresh(true);
+        }
+        if scope.sensors {
+            self.components.refresh(false);
+        }
+        if scope.disks {
+            self.disks.refresh(true);
+        }
+
+        let sampled_at = Instant::now();
+        let counters_elapsed = sampled_at.duration_since(self.prev_counters_at);
+        let elapsed_secs = counters_elapsed.as_secs_f64();
+        let rate = |delta: u64| {
+            if counters_elapsed >= min_interval {
+                delta as f64 / elapsed_secs
+            } else {
+                0.0
+            }
+        };
+
+        let metrics = &mut self.metrics;
+        metrics.cpu_usage = self.system.global_cpu_usage() as f64;
+        metrics.cpu_usages = self
+            .system
+            .cpus()
+            .iter()
+            .map(|c| c.cpu_usage() as f64)
+            .collect();
+        metrics.cpu_temperature = cpu_temperature(&self.components);
+        metrics.cpu_frequency = cpu_frequency(&self.system);
+        let cpu_times = cpustat::read_cpu_times();
+        metrics.cpu_breakdown = cpu_times
+            .zip(self.prev_cpu_times)
+            .and_then(|(now, previous)| now.breakdown_since(&previous));
+        self.prev_cpu_times = cpu_times;
+        metrics.used_memory = self.system.used_memory();
+        metrics.cached_memory = cached_memory(&self.system);
+        metrics.available_memory = self.system.available_memory();
+        metrics.total_memory = self.system.total_memory();
+        metrics.memory_percent = memory_percent(&self.system, self.memory_available);
+        metrics.swap_percent = swap_percent(&self.system);
+        metrics.used_swap = self.system.used_swap();
+        metrics.total_swap = self.system.total_swap();
+
+        if scope.network {
+            let counters =
+                network_counters(&self.networks, self.all_interfaces, &self.interface_filter);
+            if !self.prev_scope.network {
+                self.prev_network_counters = counters.clone();
+            }
+            metrics.interface_rates = counters
+                .iter()
+                .map(|(name, &(received, transmitted))| {
+                    let (prev_received, prev_transmitted) = self
+                        .prev_network_counters
+                        .get(name)
+                        .copied()
+                        .unwrap_or((received, transmitted));
+                    InterfaceRate {
+                        name: name.clone(),
+                        download: rate(received.saturating_sub(prev_received)),
+                        upload: rate(transmitted.saturating_sub(prev_transmitted)),
+                        reset: received < prev_received || transmitted < prev_transmitted,
+                    }
+                })
+                .collect();
+            metrics.interface_rates.sort_by(|a, b| a.name.cmp(&b.name));
+
+            metrics.network_download_rate =
+                metrics.interface_rates.iter().map(|r| r.download).sum();
+            metrics.network_upload_rate = metrics.interface_rates.iter().map(|r| r.upload).sum();
+            metrics.network_counter_reset = metrics.interface_rates.iter().any(|r| r.reset);
+
+            self.prev_network_counters = counters;
+
+            metrics
+                .network_download_history
+                .push_back(metrics.network_download_rate);
+            metrics
+                .network_upload_history
+                .push_back(metrics.network_upload_rate);
+            metrics.trim_history();
+        }
+
+        if scope.processes {
+            let (disk_read, disk_written) = if self.prev_scope.processes {
+                disk_io_bytes(&self.system)
+            } else {
+                (0, 0)
+            };
+            metrics.disk_read_rate = rate(disk_read);
+            metrics.disk_write_rate = rate(disk_written);
+        }
+        let kernel_counters = cpustat::read_kernel_counters();
+        metrics.kernel_rates =
+            kernel_counters
+                .zip(self.prev_kernel_counters)
+                .map(|(now, previous)| KernelRates {
+                    context_switches: rate(
+                        now.context_switches
+                            .saturating_sub(previous.context_switches),
+                    ),
+                    interrupts: rate(now.interrupts.saturating_sub(previous.interrupts)),
+                });
+        self.prev_kernel_counters = kernel_counters;
+        let swap_counters = vmstat::read_swap_counters();
+        metrics.swap_rates = swap_counters
+            .zip(self.prev_swap_counters)
+            .map(|(now, previous)| SwapRates {
+                pages_in: rate(now.pages_in.saturating_sub(previous.pages_in)),
+                pages_out: rate(now.pages_out.saturating_sub(previous.pages_out)),
+            });
+        self.prev_swap_counters = swap_counters;
+        self.prev_counters_at = sampled_at;
+        self.prev_scope = scope;
+
+        metrics.battery = battery::read_battery();
+        metrics.gpu = self.nvml.as_ref().and_then(gpu::read_gpu);
+        metrics.numa_nodes = numa::read_numa_nodes();
+
+        let load_avg = System::load_average();
+        metrics.load_avg_1 = load_avg.one;
+        metrics.load_avg_5 = load_avg.five;
+        metrics.load_avg_15 = load_avg.fifteen;
+
+        (metrics.process_count, metrics.thread_count) = process_counts(&self.system);
+        metrics.uptime = System::uptime();
+        metrics.processes = if scope.processes {
+            processes(&self.system)
+        } else {
+            Vec::new()
+        };
+        metrics.sensors = sensors(&self.components);
+        metrics.disks = disk_space(&self.disks);
+    }
+
+    pub fn refresh_cpu(&mut self) {
+        self.system.refresh_cpu_all();
+        self.metrics.cpu_usage = self.system.global_cpu_usage() as f64;
+        self.metrics.uptime = System::uptime();
+    }
+
+    pub fn set_history_len(&mut self, len: usize) {
+        self.metrics.set_history_len(len);
+    }
+
+    pub fn reset_baseline(&mut self) {
+        self.networks.refresh(true);
+        self.prev_network_counters =
+            network_counters(&self.networks, self.all_interfaces, &self.interface_filter);
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing().with_disk_usage(),
+        );
+        self.prev_kernel_counters = cpustat::read_kernel_counters();
+        self.prev_swap_counters = vmstat::read_swap_counters();
+        self.prev_counters_at = Instant::now();
+    }
+}
+
+impl Metrics {
+    pub fn set_history_len(&mut self, len: usize) {
+        self.history_len = len.max(2);
+        self.trim_history();
+    }
+
+    fn trim_history(&mut self) {
+        while self.network_download_history.len() > self.history_len {
+            self.network_download_history.pop_front();
+            self.network_upload_history.pop_front();
+        }
+    }
+
+    pub fn process(&self, pid: Pid) -> Option<&ProcessInfo> {
+        self.processes.iter().find(|process| process.pid == pid)
+    }
+
+    pub fn find_process(&self, name: &str) -> Option<&ProcessInfo> {
+        let name = name.to_lowercase();
+        self.processes
+            .iter()
+            .filter(|process| !process.thread && process.name.to_lowercase().contains(&name))
+            .min_by_key(|process| process.pid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> InterfaceFilter {
+        InterfaceFilter {
+            include: include.iter().map(|p| p.to_string()).collect(),
+            exclude: exclude.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn empty_filter_allows_everything() {
+        assert!(InterfaceFilter::default().allows("eth0"));
+    }
+
+    #[test]
+    fn include_patterns_restrict_interfaces() {
+        let filter = filter(&["eth*", "wlan?"], &[]);
+        assert!(filter.allows("eth0"));
+        assert!(filter.allows("wlan1"));
+        assert!(!filter.allows("wlan10"));
+        assert!(!filter.allows("docker0"));
+    }
+
+    #[test]
+    fn exclude_patterns_win_over_include() {
+        let filter = filter(&["*"], &["veth*", "docker?"]);
+        assert!(filter.allows("eth0"));
+        assert!(!filter.allows("veth1a2b"));
+        assert!(!filter.allows("docker0"));
+    }
+
+    #[test]
+    fn glob_matches_whole_name() {
+        assert!(glob_match("en*s0", "enp3s0"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("eth", "eth0"));
+        assert!(!glob_match("?", ""));
+    }
+}
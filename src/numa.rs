@@ -0,0 +1,58 @@
+use std::fs;
+
+#[derive(Clone)]
+pub struct NumaNode {
+    pub id: usize,
+    pub total: u64,
+    pub used: u64,
+}
+
+impl NumaNode {
+    pub fn percent(&self) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => self.used as f64 / total as f64 * 100.0,
+        }
+    }
+}
+
+fn read_node(id: usize) -> Option<NumaNode> {
+    let meminfo = fs::read_to_string(format!("/sys/devices/system/node/node{id}/meminfo")).ok()?;
+    let field = |name: &str| {
+        meminfo.lines().find_map(|line| {
+            let mut parts = line.split_whitespace().skip(2);
+            (parts.next()? == name)
+                .then(|| parts.next()?.parse::<u64>().ok())
+                .flatten()
+                .map(|kib| kib * 1024)
+        })
+    };
+    let total = field("MemTotal:")?;
+    let free = field("MemFree:")?;
+
+    Some(NumaNode {
+        id,
+        total,
+        used: total.saturating_sub(free),
+    })
+}
+
+pub fn read_numa_nodes() -> Vec<NumaNode> {
+    let Ok(entries) = fs::read_dir("/sys/devices/system/node") else {
+        return Vec::new();
+    };
+    let mut nodes: Vec<NumaNode> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()
+        })
+        .filter_map(read_node)
+        .collect();
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
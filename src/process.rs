@@ -0,0 +1,196 @@
+use ratatui::{
+    Frame,
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::{Row, Table, TableState},
+};
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, Process, ProcessesToUpdate, Signal, System};
+
+use zemon::{metrics::ProcessInfo, units::format_megabytes};
+
+use crate::theme::Theme;
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortKey {
+    CpuUsage,
+    Memory,
+    Pid,
+    Name,
+}
+
+impl SortKey {
+    pub fn next(&self) -> Self {
+        match self {
+            SortKey::CpuUsage => SortKey::Memory,
+            SortKey::Memory => SortKey::Pid,
+            SortKey::Pid => SortKey::Name,
+            SortKey::Name => SortKey::CpuUsage,
+        }
+    }
+
+    fn column(&self) -> usize {
+        match self {
+            SortKey::Pid => 0,
+            SortKey::Name => 1,
+            SortKey::CpuUsage => 2,
+            SortKey::Memory => 3,
+        }
+    }
+}
+
+pub struct PendingKill {
+    pub pid: Pid,
+    pub name: String,
+    pub start_time: u64,
+    pub signal: Signal,
+}
+
+impl PendingKill {
+    pub fn signal_name(&self) -> &str {
+        match self.signal {
+            Signal::Kill => "SIGKILL",
+            _ => "SIGTERM",
+        }
+    }
+}
+
+pub fn kill_process(kill: &PendingKill) -> String {
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[kill.pid]), true);
+    let same_process = |process: &&Process| {
+        process.name().to_string_lossy() == kill.name && process.start_time() == kill.start_time
+    };
+    match system.process(kill.pid).filter(same_process) {
+        Some(process) => match process.kill_with(kill.signal) {
+            Some(true) => format!(
+                "Sent {} to {} ({})",
+                kill.signal_name(),
+                kill.pid,
+                kill.name
+            ),
+            Some(false) => format!("Failed to signal {} ({})", kill.pid, kill.name),
+            None => format!("{} is not supported", kill.signal_name()),
+        },
+        None => format!("Process {} ({}) has already exited", kill.pid, kill.name),
+    }
+}
+
+pub struct ProcessView {
+    pub processes: Vec<ProcessInfo>,
+    pub state: TableState,
+    pub sort_key: SortKey,
+    pub filter: String,
+    pub editing_filter: bool,
+}
+
+impl ProcessView {
+    pub fn new() -> ProcessView {
+        ProcessView {
+            processes: Vec::new(),
+            state: TableState::default().with_selected(0),
+            sort_key: SortKey::CpuUsage,
+            filter: String::new(),
+            editing_filter: false,
+        }
+    }
+
+    pub fn refresh(&mut self, processes: &[ProcessInfo]) {
+        let filter = self.filter.to_lowercase();
+        self.processes = processes
+            .iter()
+            .filter(|process| filter.is_empty() || process.name.to_lowercase().contains(&filter))
+            .cloned()
+            .collect();
+        self.sort();
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort_key = self.sort_key.next();
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        let sort_key = self.sort_key;
+        self.processes.sort_by(|a, b| {
+            match sort_key {
+                SortKey::CpuUsage => b.cpu_usage.total_cmp(&a.cpu_usage),
+                SortKey::Memory => b.memory.cmp(&a.memory),
+                SortKey::Pid => a.pid.cmp(&b.pid),
+                SortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            }
+            .then(a.pid.cmp(&b.pid))
+        });
+
+        if let Some(selected) = self.state.selected() {
+            let last = self.processes.len().saturating_sub(1);
+            self.state.select(Some(selected.min(last)));
+        }
+    }
+
+    pub fn selected(&self) -> Option<&ProcessInfo> {
+        self.state
+            .selected()
+            .and_then(|index| self.processes.get(index))
+    }
+
+    pub fn select_next(&mut self) {
+        let last = self.processes.len().saturating_sub(1);
+        let selected = self.state.selected().unwrap_or(0);
+        self.state.select(Some((selected + 1).min(last)));
+    }
+
+    pub fn select_prev(&mut self) {
+        let selected = self.state.selected().unwrap_or(0);
+        self.state.select(Some(selected.saturating_sub(1)));
+    }
+
+    fn title(&self) -> String {
+        match (self.editing_filter, self.filter.is_empty()) {
+            (true, _) => format!(" Processes /{}_ ", self.filter),
+            (false, false) => format!(" Processes /{} ", self.filter),
+            (false, true) => " Processes ".to_string(),
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: ratatui::prelude::Rect, theme: &Theme, si: bool) {
+        let header_cells =
+            ["PID", "Name", "CPU%", "Memory"]
+                .iter()
+                .enumerate()
+                .map(|(column, title)| {
+                    if column == self.sort_key.column() {
+                        format!("{title} ▾")
+                    } else {
+                        title.to_string()
+                    }
+                });
+        let header = Row::new(header_cells)
+            .style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD));
+
+        let rows = self.processes.iter().map(|process| {
+            Row::new(vec![
+                process.pid.to_string(),
+                process.name.clone(),
+                format!("{:.1}", process.cpu_usage),
+                format_megabytes(process.memory, si),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Min(10),
+                Constraint::Length(7),
+                Constraint::Length(12),
+            ],
+        )
+        .header(header)
+        .block(theme.block(self.title()))
+        .row_highlight_style(Style::default().bg(theme.muted));
+
+        f.render_stateful_widget(table, area, &mut self.state);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    App, Tab,
+    config::{config_dir, validate_interval},
+    process::SortKey,
+    theme::ThemeName,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub tab: Tab,
+    pub sort_key: SortKey,
+    pub theme: ThemeName,
+    pub interval: f64,
+}
+
+fn state_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("state.toml"))
+}
+
+impl State {
+    pub fn from_app(app: &App) -> State {
+        State {
+            tab: app.current_tab,
+            sort_key: app.process_view.sort_key,
+            theme: app.theme_name,
+            interval: app.refresh_interval.as_secs_f64(),
+        }
+    }
+
+    pub fn load() -> Option<State> {
+        let content = fs::read_to_string(state_path()?).ok()?;
+        toml::from_str(&content)
+            .ok()
+            .filter(|state: &State| validate_interval(state.interval).is_ok())
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let Some(path) = state_path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, toml::to_string(self)?)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+#[derive(Clone, Copy)]
+pub struct Stats {
+    pub min: f64,
+    pub max: f64,
+    sum: f64,
+    count: u64,
+}
+
+impl Stats {
+    pub fn new() -> Stats {
+        Stats {
+            min: 0.0,
+            max: 0.0,
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    pub fn record(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum / self.count as f64
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "min {:.0}% avg {:.0}% max {:.0}%",
+            self.min,
+            self.mean(),
+            self.max
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use clap::ValueEnum;
+use ratatui::{
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    Dark,
+    Light,
+    Mono,
+    Colorblind,
+}
+
+pub struct Theme {
+    pub levels: [Color; 4],
+    pub border: Color,
+    pub text: Color,
+    pub muted: Color,
+    pub download: Color,
+    pub upload: Color,
+}
+
+impl ThemeName {
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeName::Dark => ThemeName::Light,
+            ThemeName::Light => ThemeName::Mono,
+            ThemeName::Mono => ThemeName::Colorblind,
+            ThemeName::Colorblind => ThemeName::Dark,
+        }
+    }
+
+    pub fn theme(&self) -> Theme {
+        match self {
+            ThemeName::Dark => Theme {
+                levels: [Color::Blue, Color::Cyan, Color::Yellow, Color::Red],
+                border: Color::Reset,
+                text: Color::Gray,
+                muted: Color::DarkGray,
+                download: Color::Cyan,
+                upload: Color::Magenta,
+            },
+            ThemeName::Light => Theme {
+                levels: [Color::Blue, Color::Green, Color::Magenta, Color::Red],
+                border: Color::Black,
+                text: Color::Black,
+                muted: Color::DarkGray,
+                download: Color::Blue,
+                upload: Color::Magenta,
+            },
+            ThemeName::Mono => Theme {
+                levels: [Color::DarkGray, Color::Gray, Color::Gray, Color::White],
+                border: Color::Gray,
+                text: Color::White,
+                muted: Color::DarkGray,
+                download: Color::White,
+                upload: Color::Gray,
+            },
+            ThemeName::Colorblind => Theme {
+                levels: [
+                    Color::Rgb(0, 68, 136),
+                    Color::Rgb(86, 180, 233),
+                    Color::Rgb(230, 159, 0),
+                    Color::Rgb(213, 94, 0),
+                ],
+                border: Color::Reset,
+                text: Color::Gray,
+                muted: Color::DarkGray,
+                download: Color::Rgb(86, 180, 233),
+                upload: Color::Rgb(230, 159, 0),
+            },
+        }
+    }
+}
+
+impl Theme {
+    pub fn block<'a>(&self, title: impl Into<Line<'a>>) -> Block<'a> {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border))
+            .title(title)
+    }
+}
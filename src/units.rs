@@ -0,0 +1,60 @@
+pub fn format_gigabytes(bytes: u64, si: bool) -> String {
+    if si {
+        format!("{:.1} GB", bytes as f64 / 1000.0 / 1000.0 / 1000.0)
+    } else {
+        format!("{:.1} GiB", bytes as f64 / 1024.0 / 1024.0 / 1024.0)
+    }
+}
+
+pub fn format_megabytes(bytes: u64, si: bool) -> String {
+    if si {
+        format!("{:.1} MB", bytes as f64 / 1000.0 / 1000.0)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / 1024.0 / 1024.0)
+    }
+}
+
+pub fn format_event_rate(per_sec: f64) -> String {
+    match per_sec {
+        r if r < 1000.0 => format!("{r:.0}/s"),
+        r if r < 1000.0 * 1000.0 => format!("{:.1}K/s", r / 1000.0),
+        r => format!("{:.1}M/s", r / 1000.0 / 1000.0),
+    }
+}
+
+pub fn format_bit_rate(bytes_per_sec: f64) -> String {
+    match bytes_per_sec * 8.0 {
+        r if r < 1000.0 => format!("{r:.1} bps"),
+        r if r < 1000.0 * 1000.0 => format!("{:.1} Kbps", r / 1000.0),
+        r if r < 1000.0 * 1000.0 * 1000.0 => format!("{:.1} Mbps", r / 1000.0 / 1000.0),
+        r => format!("{:.1} Gbps", r / 1000.0 / 1000.0 / 1000.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_binary_and_si_sizes() {
+        assert_eq!(format_gigabytes(1024 * 1024 * 1024, false), "1.0 GiB");
+        assert_eq!(format_gigabytes(1_500_000_000, true), "1.5 GB");
+        assert_eq!(format_megabytes(512 * 1024, false), "0.5 MiB");
+        assert_eq!(format_megabytes(2_000_000, true), "2.0 MB");
+    }
+
+    #[test]
+    fn scales_event_rates() {
+        assert_eq!(format_event_rate(999.0), "999/s");
+        assert_eq!(format_event_rate(1500.0), "1.5K/s");
+        assert_eq!(format_event_rate(2_500_000.0), "2.5M/s");
+    }
+
+    #[test]
+    fn converts_bytes_to_bit_rates() {
+        assert_eq!(format_bit_rate(100.0), "800.0 bps");
+        assert_eq!(format_bit_rate(1000.0), "8.0 Kbps");
+        assert_eq!(format_bit_rate(1_000_000.0), "8.0 Mbps");
+        assert_eq!(format_bit_rate(1_000_000_000.0), "8.0 Gbps");
+    }
+}
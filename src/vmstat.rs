@@ -0,0 +1,50 @@
+use std::fs;
+
+#[derive(Clone, Copy)]
+pub struct SwapCounters {
+    pub pages_in: u64,
+    pub pages_out: u64,
+}
+
+#[derive(Clone)]
+pub struct SwapRates {
+    pub pages_in: f64,
+    pub pages_out: f64,
+}
+
+pub fn read_swap_counters() -> Option<SwapCounters> {
+    parse_swap_counters(&fs::read_to_string("/proc/vmstat").ok()?)
+}
+
+pub fn parse_swap_counters(vmstat: &str) -> Option<SwapCounters> {
+    let field = |name: &str| {
+        vmstat
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))?
+            .trim()
+            .parse()
+            .ok()
+    };
+    Some(SwapCounters {
+        pages_in: field("pswpin")?,
+        pages_out: field("pswpout")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_swap_counters() {
+        let vmstat = "pswpin 12\npswpout 34\npswpin_extra 99\n";
+        let counters = parse_swap_counters(vmstat).unwrap();
+        assert_eq!(counters.pages_in, 12);
+        assert_eq!(counters.pages_out, 34);
+    }
+
+    #[test]
+    fn ignores_fields_sharing_a_prefix() {
+        assert!(parse_swap_counters("pswpin_extra 1\npswpout 2\n").is_none());
+    }
+}
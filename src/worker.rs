@@ -0,0 +1,90 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use zemon::metrics::{Metrics, Sampler, Scope};
+
+#[derive(Clone, Copy)]
+pub enum Request {
+    Full(Duration, Scope),
+    Cpu,
+}
+
+enum Command {
+    Sample(Request),
+    SetHistoryLen(usize),
+    ResetBaseline,
+}
+
+pub struct Worker {
+    commands: Sender<Command>,
+    samples: Receiver<(Request, Metrics)>,
+    pending: bool,
+}
+
+impl Worker {
+    pub fn spawn(mut sampler: Sampler) -> Worker {
+        let (commands, command_receiver) = mpsc::channel();
+        let (sample_sender, samples) = mpsc::channel();
+        thread::spawn(move || {
+            for command in command_receiver {
+                match command {
+                    Command::Sample(request) => {
+                        match request {
+                            Request::Full(min_interval, scope) => {
+                                sampler.refresh(min_interval, scope)
+                            }
+                            Request::Cpu => sampler.refresh_cpu(),
+                        }
+                        if sample_sender
+                            .send((request, sampler.metrics.clone()))
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                    Command::SetHistoryLen(len) => sampler.set_history_len(len),
+                    Command::ResetBaseline => sampler.reset_baseline(),
+                }
+            }
+        });
+        Worker {
+            commands,
+            samples,
+            pending: false,
+        }
+    }
+
+    pub fn request(&mut self, request: Request) -> bool {
+        if self.pending {
+            return false;
+        }
+        self.pending = self.commands.send(Command::Sample(request)).is_ok();
+        self.pending
+    }
+
+    pub fn try_recv(&mut self) -> Option<(Request, Metrics)> {
+        let sample = self.samples.try_recv().ok()?;
+        self.pending = false;
+        Some(sample)
+    }
+
+    pub fn recv(&mut self) -> Option<(Request, Metrics)> {
+        if !self.pending {
+            return None;
+        }
+        let sample = self.samples.recv().ok()?;
+        self.pending = false;
+        Some(sample)
+    }
+
+    pub fn set_history_len(&self, len: usize) {
+        let _ = self.commands.send(Command::SetHistoryLen(len));
+    }
+
+    pub fn reset_baseline(&self) {
+        let _ = self.commands.send(Command::ResetBaseline);
+    }
+}